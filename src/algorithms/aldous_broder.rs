@@ -1,10 +1,12 @@
-use super::On;
+use super::{events::Event, On};
 use crate::{
     grids::base_grid::BaseGrid,
     utils::random::{random_cell, random_neighbor},
 };
+use rand::RngCore;
 
 /// The Aldous-Broder algorithm for generating a maze.
+#[derive(Default)]
 pub struct AldousBroder {}
 
 impl AldousBroder {
@@ -19,15 +21,26 @@ impl On for AldousBroder {
     /// # Arguments
     ///
     /// * `grid` - The grid on which to generate the maze.
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let mut cell = random_cell(grid);
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut cell = random_cell(grid, rng);
         let mut unvisited = (grid.rows() * grid.columns()) - 1;
+        observer(Event::Visited(cell.borrow().to_row_and_column()));
 
         while unvisited > 0 {
-            let neighbor = random_neighbor(grid, &cell.borrow().neighbors());
+            let neighbor = random_neighbor(grid, &cell.borrow().neighbors(), rng);
+            let position = neighbor.borrow().to_row_and_column();
+            observer(Event::Visited(position));
 
             if neighbor.borrow_mut().links().is_empty() {
                 cell.borrow_mut().link(neighbor.clone());
+                observer(Event::Linked(cell.borrow().to_row_and_column(), position));
                 unvisited -= 1;
             }
 
@@ -46,7 +59,7 @@ mod tests {
         let mut grid = Grid::new(5, 5);
         let aldous_broder = AldousBroder::new();
 
-        aldous_broder.on(&mut grid);
+        aldous_broder.on(&mut grid, &mut rand::thread_rng());
 
         assert_eq!(grid.rows(), 5);
         assert_eq!(grid.columns(), 5);
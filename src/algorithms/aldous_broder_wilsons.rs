@@ -0,0 +1,115 @@
+use super::{events::Event, wilsons::Wilsons, On};
+use crate::{
+    grids::base_grid::BaseGrid,
+    utils::random::{random_cell, random_neighbor},
+};
+use rand::RngCore;
+
+/// The `AldousBroderWilsons` struct represents a hybrid of the Aldous-Broder and the Wilson's
+/// algorithms.
+///
+/// The Aldous-Broder algorithm quickly visits the first cells but takes long to find the last
+/// unvisited ones, while the Wilson's algorithm is slow to start, when its random walks look for
+/// a maze made of a single cell, and fast to finish. The hybrid runs the Aldous-Broder algorithm
+/// until a fraction of the cells is visited, then completes the maze with the Wilson's algorithm.
+/// Both algorithms generate uniform spanning trees, and so does their combination.
+pub struct AldousBroderWilsons {
+    fraction: f64,
+}
+
+impl AldousBroderWilsons {
+    pub fn new(fraction: f64) -> Self {
+        Self {
+            fraction: fraction.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the fraction of the cells visited by the Aldous-Broder algorithm, between `0`
+    /// and `1`.
+    pub fn fraction(&self) -> f64 {
+        self.fraction
+    }
+}
+
+impl On for AldousBroderWilsons {
+    /// Generates a maze using the Aldous-Broder algorithm, then the Wilson's algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let columns = grid.columns();
+        let mut visited = vec![false; (grid.rows() * columns) as usize];
+        let target = ((visited.len() as f64 * self.fraction).ceil() as usize).max(1);
+
+        let mut cell = random_cell(grid, rng);
+        let (row, column) = cell.borrow().to_row_and_column();
+        visited[(row * columns + column) as usize] = true;
+        observer(Event::Visited((row, column)));
+
+        let mut count = 1;
+
+        while count < target {
+            let neighbor = random_neighbor(grid, &cell.borrow().neighbors(), rng);
+            let (row, column) = neighbor.borrow().to_row_and_column();
+            let index = (row * columns + column) as usize;
+            observer(Event::Visited((row, column)));
+
+            if !visited[index] {
+                cell.borrow_mut().link(neighbor.clone());
+                observer(Event::Linked(
+                    cell.borrow().to_row_and_column(),
+                    (row, column),
+                ));
+                visited[index] = true;
+                count += 1;
+            }
+
+            cell = neighbor;
+        }
+
+        Wilsons::new().on_visited(grid, &mut visited, rng, observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::assert_perfect, grids::grid::Grid};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_aldous_broder_wilsons() {
+        for fraction in [0.0, 0.3, 0.5, 1.0] {
+            let mut grid = Grid::new(12, 9);
+            let hybrid = AldousBroderWilsons::new(fraction);
+
+            hybrid.on(&mut grid, &mut StdRng::seed_from_u64(11));
+
+            assert_perfect(&grid);
+        }
+    }
+
+    #[test]
+    fn test_fraction_is_clamped() {
+        assert_eq!(AldousBroderWilsons::new(-1.0).fraction(), 0.0);
+        assert_eq!(AldousBroderWilsons::new(0.25).fraction(), 0.25);
+        assert_eq!(AldousBroderWilsons::new(3.0).fraction(), 1.0);
+    }
+
+    #[test]
+    fn test_large_grid() {
+        let mut grid = Grid::new(150, 150);
+
+        AldousBroderWilsons::new(0.5).on(&mut grid, &mut StdRng::seed_from_u64(3));
+
+        assert_perfect(&grid);
+    }
+}
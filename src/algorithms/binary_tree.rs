@@ -1,6 +1,6 @@
-use super::On;
+use super::{events::Event, On};
 use crate::grids::base_grid::BaseGrid;
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 /// Implements the binary tree algorithm for generating mazes.
 ///
@@ -11,15 +11,16 @@ use rand::Rng;
 /// # Examples
 ///
 /// ```
-/// use mazes::grid::Grid;
-/// use mazes::binary_tree::BinaryTree;
+/// use mazes::algorithms::{binary_tree::BinaryTree, On};
+/// use mazes::Grid;
 ///
 /// let mut grid = Grid::new(5, 5);
-/// BinaryTree::on(&mut grid);
+/// BinaryTree::new().on(&mut grid, &mut rand::thread_rng());
 /// ```
 ///
 /// In this example, a 5x5 grid is created and the binary tree algorithm is applied to it,
 /// generating a maze with a bias towards paths that go either north or east.
+#[derive(Default)]
 pub struct BinaryTree {}
 
 impl BinaryTree {
@@ -34,25 +35,32 @@ impl On for BinaryTree {
     /// # Arguments
     ///
     /// * `grid` - A mutable reference to the grid on which to apply the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
     ///
     /// # Examples
     ///
     /// ```
-    /// use mazes::grid::Grid;
-    /// use mazes::binary_tree::BinaryTree;
+    /// use mazes::algorithms::{binary_tree::BinaryTree, On};
+    /// use mazes::Grid;
     ///
     /// let mut grid = Grid::new(5, 5);
-    /// BinaryTree::on(&mut grid);
+    /// BinaryTree::new().on(&mut grid, &mut rand::thread_rng());
     /// ```
     ///
     /// In this example, a 5x5 grid is created and the binary tree algorithm is applied to it,
     /// generating a maze with a bias towards paths that go either north or east.
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let mut rng = rand::thread_rng();
-
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
         for cell in grid.iter() {
             let mut neighbors = vec![];
             let mut cell = cell.borrow_mut();
+            let position = cell.to_row_and_column();
+            observer(Event::Visited(position));
 
             if let Some(north) = cell.north() {
                 neighbors.push(north);
@@ -71,6 +79,7 @@ impl On for BinaryTree {
             let neighbor = grid.cell(neighbor_row, neighbor_column).unwrap().to_owned();
 
             cell.link(neighbor);
+            observer(Event::Linked(position, (neighbor_row, neighbor_column)));
         }
     }
 }
@@ -85,7 +94,7 @@ mod tests {
         let mut grid = Grid::new(3, 3);
         let binary_tree = BinaryTree::new();
 
-        binary_tree.on(&mut grid);
+        binary_tree.on(&mut grid, &mut rand::thread_rng());
 
         // Verify that each cell is linked to either its north or east neighbor
         for cell in grid.iter() {
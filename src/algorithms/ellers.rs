@@ -0,0 +1,333 @@
+use super::{events::Event, On};
+use crate::grids::base_grid::BaseGrid;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use std::{collections::HashMap, fmt::Display};
+
+/// A row of a maze generated by the Eller's algorithm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    index: usize,
+    east: Vec<bool>,
+    south: Vec<bool>,
+}
+
+impl Row {
+    /// Returns the index of the row, starting from zero.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of columns of the row.
+    pub fn columns(&self) -> usize {
+        self.south.len()
+    }
+
+    /// Returns whether the cell at the given column is linked to its eastern neighbor.
+    pub fn linked_east(&self, column: usize) -> bool {
+        self.east.get(column).copied().unwrap_or(false)
+    }
+
+    /// Returns whether the cell at the given column is linked to the cell below it.
+    pub fn linked_south(&self, column: usize) -> bool {
+        self.south.get(column).copied().unwrap_or(false)
+    }
+
+    /// Returns the northern boundary of a maze, drawn above its first row.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns of the maze.
+    pub fn header(columns: usize) -> String {
+        format!("+{}\n", "---+".repeat(columns))
+    }
+}
+
+impl Display for Row {
+    /// Formats the row with the same ASCII characters as `GridDisplay`: the cells with their
+    /// eastern walls, then their southern walls.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut top = String::from("|");
+        let mut bottom = String::from("+");
+
+        for column in 0..self.columns() {
+            top.push_str(if self.linked_east(column) {
+                "    "
+            } else {
+                "   |"
+            });
+            bottom.push_str(if self.linked_south(column) {
+                "   +"
+            } else {
+                "---+"
+            });
+        }
+
+        writeln!(f, "{top}")?;
+        writeln!(f, "{bottom}")
+    }
+}
+
+/// Generates the rows of an Eller's maze one at a time.
+///
+/// Only the sets of the cells of the current row are kept in memory, so the stream can generate
+/// mazes of any height. The stream never ends: the last row of the maze, which joins every
+/// remaining set, is returned by `finish`.
+pub struct Stream<R: RngCore> {
+    rng: R,
+    index: usize,
+    sets: Vec<usize>,
+    next_set: usize,
+}
+
+impl<R: RngCore> Stream<R> {
+    /// Creates a new stream of rows with the given number of columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns of the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    ///
+    /// # Returns
+    ///
+    /// A new `Stream` instance.
+    pub fn new(columns: usize, rng: R) -> Self {
+        Self {
+            rng,
+            index: 0,
+            sets: (0..columns).collect(),
+            next_set: columns,
+        }
+    }
+
+    /// Returns the last row of the maze, which joins every remaining set.
+    pub fn finish(mut self) -> Row {
+        self.row(true)
+    }
+
+    /// Generates the next row.
+    ///
+    /// The adjacent cells of different sets are randomly linked, merging their sets; then, unless
+    /// this is the last row, at least one cell of every set is linked to the row below, where the
+    /// cells which are not linked from above start a set of their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `last` - Whether this is the last row, whose cells are all linked in a single set.
+    ///
+    /// # Returns
+    ///
+    /// The generated `Row`.
+    fn row(&mut self, last: bool) -> Row {
+        let columns = self.sets.len();
+        let mut east = vec![false; columns];
+        let mut south = vec![false; columns];
+
+        for column in 1..columns {
+            let (west_set, set) = (self.sets[column - 1], self.sets[column]);
+
+            if west_set != set && (last || self.rng.gen_bool(0.5)) {
+                east[column - 1] = true;
+
+                for member in self.sets.iter_mut().filter(|member| **member == set) {
+                    *member = west_set;
+                }
+            }
+        }
+
+        if !last {
+            let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+
+            for (column, set) in self.sets.iter().enumerate() {
+                members.entry(*set).or_default().push(column);
+            }
+
+            let mut sets = members.into_iter().collect::<Vec<(usize, Vec<usize>)>>();
+            sets.sort_unstable();
+
+            for (_, mut columns) in sets {
+                columns.shuffle(&mut self.rng);
+
+                for (position, column) in columns.into_iter().enumerate() {
+                    if position == 0 || self.rng.gen_range(0..3) == 0 {
+                        south[column] = true;
+                    }
+                }
+            }
+
+            for (column, set) in self.sets.iter_mut().enumerate() {
+                if !south[column] {
+                    *set = self.next_set;
+                    self.next_set += 1;
+                }
+            }
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        Row { index, east, south }
+    }
+}
+
+impl<R: RngCore> Iterator for Stream<R> {
+    type Item = Row;
+
+    /// Returns the next row of the maze; it never returns `None`.
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.row(false))
+    }
+}
+
+/// The `Ellers` struct represents the Eller's algorithm.
+///
+/// The algorithm builds the maze one row at a time, keeping track of the set every cell of the
+/// row belongs to: cells of the same set are connected, so linking cells of different sets
+/// never creates a loop.
+#[derive(Default)]
+pub struct Ellers {}
+
+impl Ellers {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Returns an endless stream of rows, generated without any grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - The number of columns of the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    ///
+    /// # Returns
+    ///
+    /// A `Stream` of rows.
+    pub fn stream<R: RngCore>(&self, columns: usize, rng: R) -> Stream<R> {
+        Stream::new(columns, rng)
+    }
+}
+
+impl On for Ellers {
+    /// Executes the Eller's algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut stream = self.stream(grid.columns() as usize, rng);
+        let rows = grid.rows() as usize;
+
+        for (index, cells) in grid.each_row().enumerate() {
+            let row = stream.row(index == rows - 1);
+
+            for (column, cell) in cells.iter().enumerate() {
+                let mut cell = cell.borrow_mut();
+                let position = cell.to_row_and_column();
+                observer(Event::Visited(position));
+
+                if row.linked_east(column) {
+                    let east = cell.east().unwrap();
+                    cell.link(grid.cell(east.0, east.1).unwrap().clone());
+                    observer(Event::Linked(position, east));
+                }
+
+                if row.linked_south(column) {
+                    let south = cell.south().unwrap();
+                    cell.link(grid.cell(south.0, south.1).unwrap().clone());
+                    observer(Event::Linked(position, south));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::assert_perfect,
+        grids::{base_grid::WithRowsAndColumns, grid::Grid},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_ellers() {
+        let mut grid = Grid::new(6, 8);
+        let ellers = Ellers::new();
+
+        ellers.on(&mut grid, &mut rand::thread_rng());
+
+        assert_perfect(&grid);
+    }
+
+    #[test]
+    fn test_stream() {
+        let mut stream = Ellers::new().stream(8, StdRng::seed_from_u64(7));
+        let mut rows = stream.by_ref().take(9).collect::<Vec<Row>>();
+        rows.push(stream.finish());
+
+        let grid = Grid::new(10, 8);
+
+        for row in &rows {
+            for column in 0..row.columns() {
+                let cell = grid.cell(row.index() as i32, column as i32).unwrap();
+
+                if row.linked_east(column) {
+                    let east = grid.cell(row.index() as i32, column as i32 + 1).unwrap();
+                    cell.borrow_mut().link(east.clone());
+                }
+
+                if row.linked_south(column) {
+                    let south = grid.cell(row.index() as i32 + 1, column as i32).unwrap();
+                    cell.borrow_mut().link(south.clone());
+                }
+            }
+        }
+
+        assert_eq!(rows.last().unwrap().index(), 9);
+        assert_perfect(&grid);
+    }
+
+    #[test]
+    fn test_stream_matches_grid() {
+        let mut grid = Grid::new(4, 5);
+        Ellers::new().on(&mut grid, &mut StdRng::seed_from_u64(3));
+
+        let mut stream = Ellers::new().stream(5, StdRng::seed_from_u64(3));
+        let mut rows = stream.by_ref().take(3).collect::<Vec<Row>>();
+        rows.push(stream.finish());
+
+        for row in rows {
+            for column in 0..5 {
+                let cell = grid.cell(row.index() as i32, column).unwrap().borrow();
+
+                assert_eq!(
+                    cell.links().contains_key(&(row.index() as i32, column + 1)),
+                    row.linked_east(column as usize)
+                );
+                assert_eq!(
+                    cell.links().contains_key(&(row.index() as i32 + 1, column)),
+                    row.linked_south(column as usize)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_row_display() {
+        let row = Row {
+            index: 0,
+            east: vec![true, false, false],
+            south: vec![false, true, false],
+        };
+
+        assert_eq!(Row::header(3), "+---+---+---+\n");
+        assert_eq!(row.to_string(), "|       |   |\n+---+   +---+\n");
+    }
+}
@@ -0,0 +1,24 @@
+/// A step of the generation of a maze, reported by a generator to its observer.
+///
+/// The events describe how the maze is carved: replaying the `Linked` and `Unlinked` events on
+/// a fresh grid of the same size rebuilds the maze, while the other events tell where the
+/// generator is, so that a renderer can highlight it frame by frame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    /// The generator moved to a cell, which may have been visited before.
+    Visited((i32, i32)),
+    /// A passage was carved between two cells.
+    Linked((i32, i32), (i32, i32)),
+    /// A wall was added between two cells.
+    Unlinked((i32, i32), (i32, i32)),
+    /// The generator is done with a cell: no more passages will be carved from it.
+    Backtracked((i32, i32)),
+    /// A loop of the random walk of the Wilson's algorithm was erased, with its cells.
+    WalkErased(Vec<(i32, i32)>),
+    /// The walk of the Hunt and Kill algorithm reached a dead end, at the given cell, and the
+    /// hunt for an unvisited cell started.
+    HuntStarted((i32, i32)),
+}
+
+/// An iterator over the events recorded while a maze was generated.
+pub type Events = std::vec::IntoIter<Event>;
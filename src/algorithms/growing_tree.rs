@@ -0,0 +1,282 @@
+use super::{events::Event, On};
+use crate::{
+    grids::base_grid::{BaseGrid, GridCell},
+    utils::random::{random_cell, random_neighbor},
+};
+use rand::{Rng, RngCore};
+use std::{fmt::Display, str::FromStr};
+
+/// The strategy used by the Growing Tree algorithm to select the next active cell.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// The most recently added cell, which behaves like the Recursive Backtracker.
+    Newest,
+    /// The least recently added cell.
+    Oldest,
+    /// A random cell, which behaves like the simplified Prim's algorithm.
+    Random,
+    /// One of the given strategies, chosen randomly according to its weight; a mix without any
+    /// weight selects the newest cell.
+    Mixed(Vec<(Selection, u32)>),
+}
+
+impl Selection {
+    /// Returns the index of the next active cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of active cells; it must be greater than zero.
+    /// * `rng` - The random number generator used by the random strategies.
+    ///
+    /// # Returns
+    ///
+    /// The index of the selected cell in the list of active cells.
+    pub fn index(&self, len: usize, rng: &mut dyn RngCore) -> usize {
+        match self {
+            Selection::Newest => len - 1,
+            Selection::Oldest => 0,
+            Selection::Random => rng.gen_range(0..len),
+            Selection::Mixed(selections) => {
+                let total = total_weight(selections);
+
+                if total == 0 {
+                    return len - 1;
+                }
+
+                let mut choice = rng.gen_range(0..total);
+
+                for (selection, weight) in selections {
+                    let weight = u64::from(*weight);
+
+                    if choice < weight {
+                        return selection.index(len, rng);
+                    }
+
+                    choice -= weight;
+                }
+
+                unreachable!("the choice is always lower than the total weight")
+            }
+        }
+    }
+}
+
+/// Returns the sum of the weights of a mix, which cannot overflow.
+fn total_weight(selections: &[(Selection, u32)]) -> u64 {
+    selections
+        .iter()
+        .map(|(_, weight)| u64::from(*weight))
+        .sum()
+}
+
+impl Display for Selection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selection::Newest => write!(f, "newest"),
+            Selection::Oldest => write!(f, "oldest"),
+            Selection::Random => write!(f, "random"),
+            Selection::Mixed(selections) => {
+                let total = total_weight(selections);
+                let parts = selections
+                    .iter()
+                    .map(|(selection, weight)| {
+                        format!("{}% {selection}", u64::from(*weight) * 100 / total.max(1))
+                    })
+                    .collect::<Vec<String>>();
+
+                write!(f, "{}", parts.join(" / "))
+            }
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    /// Parses a selection strategy: `newest`, `oldest`, `random`, or a weighted mix of them
+    /// like `newest:75,random:25`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "newest" => return Ok(Selection::Newest),
+            "oldest" => return Ok(Selection::Oldest),
+            "random" => return Ok(Selection::Random),
+            _ => {}
+        }
+
+        let mut selections = vec![];
+        let mut total: u32 = 0;
+
+        for part in value.split(',') {
+            let (name, weight) = part
+                .split_once(':')
+                .ok_or_else(|| format!("`{part}` is not a selection strategy"))?;
+
+            let selection = match name.trim() {
+                "newest" => Selection::Newest,
+                "oldest" => Selection::Oldest,
+                "random" => Selection::Random,
+                _ => return Err(format!("`{name}` is not a selection strategy")),
+            };
+
+            let weight: u32 = weight
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{weight}` is not a valid weight"))?;
+
+            total = total
+                .checked_add(weight)
+                .ok_or_else(|| format!("the weights add up to more than {}", u32::MAX))?;
+            selections.push((selection, weight));
+        }
+
+        if total == 0 {
+            return Err(String::from(
+                "at least one weight must be greater than zero",
+            ));
+        }
+
+        Ok(Selection::Mixed(selections))
+    }
+}
+
+/// The `GrowingTree` struct represents the Growing Tree algorithm.
+///
+/// The algorithm keeps a list of active cells: at every step it selects one of them, according
+/// to its `Selection` strategy, and links it to a random unvisited neighbor, which becomes active.
+/// A cell without unvisited neighbors is removed from the list.
+pub struct GrowingTree {
+    selection: Selection,
+}
+
+impl GrowingTree {
+    pub fn new(selection: Selection) -> Self {
+        Self { selection }
+    }
+
+    /// Returns the strategy used to select the next active cell.
+    pub fn selection(&self) -> &Selection {
+        &self.selection
+    }
+}
+
+impl On for GrowingTree {
+    /// Executes the Growing Tree algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut active: Vec<GridCell> = vec![random_cell(grid, rng)];
+        observer(Event::Visited(active[0].borrow().to_row_and_column()));
+
+        while !active.is_empty() {
+            let index = self.selection.index(active.len(), rng);
+            let current = &active[index];
+
+            let neighbors = current
+                .borrow()
+                .neighbors()
+                .into_iter()
+                .filter(|(row, column)| {
+                    let neighbor = grid.cell(*row, *column).unwrap();
+                    neighbor.borrow().links().is_empty()
+                })
+                .collect::<Vec<(i32, i32)>>();
+
+            let position = current.borrow().to_row_and_column();
+
+            if neighbors.is_empty() {
+                active.remove(index);
+                observer(Event::Backtracked(position));
+            } else {
+                let neighbor = random_neighbor(grid, &neighbors, rng);
+                current.borrow_mut().link(neighbor.clone());
+
+                let neighbor_position = neighbor.borrow().to_row_and_column();
+                observer(Event::Linked(position, neighbor_position));
+                observer(Event::Visited(neighbor_position));
+                active.push(neighbor);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::assert_perfect, grids::grid::Grid};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_growing_tree() {
+        let selections = vec![
+            Selection::Newest,
+            Selection::Oldest,
+            Selection::Random,
+            Selection::Mixed(vec![(Selection::Newest, 75), (Selection::Random, 25)]),
+        ];
+
+        for selection in selections {
+            let mut grid = Grid::new(6, 7);
+            let growing_tree = GrowingTree::new(selection);
+
+            growing_tree.on(&mut grid, &mut rand::thread_rng());
+
+            assert_perfect(&grid);
+        }
+    }
+
+    #[test]
+    fn test_selection_index() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        assert_eq!(Selection::Newest.index(5, &mut rng), 4);
+        assert_eq!(Selection::Oldest.index(5, &mut rng), 0);
+        assert!(Selection::Random.index(5, &mut rng) < 5);
+
+        let oldest = Selection::Mixed(vec![(Selection::Newest, 0), (Selection::Oldest, 1)]);
+        assert_eq!(oldest.index(5, &mut rng), 0);
+
+        // The weights may add up to more than a `u32`, and a mix may have none.
+        let heavy = Selection::Mixed(vec![(Selection::Oldest, u32::MAX), (Selection::Oldest, 1)]);
+        assert_eq!(heavy.index(5, &mut rng), 0);
+        assert_eq!(Selection::Mixed(vec![]).index(5, &mut rng), 4);
+    }
+
+    #[test]
+    fn test_selection_from_str() {
+        assert_eq!("newest".parse(), Ok(Selection::Newest));
+        assert_eq!("oldest".parse(), Ok(Selection::Oldest));
+        assert_eq!("random".parse(), Ok(Selection::Random));
+        assert_eq!(
+            "newest:75, random:25".parse(),
+            Ok(Selection::Mixed(vec![
+                (Selection::Newest, 75),
+                (Selection::Random, 25)
+            ]))
+        );
+        assert!("latest".parse::<Selection>().is_err());
+        assert!("newest:many".parse::<Selection>().is_err());
+        assert!("newest:0,random:0".parse::<Selection>().is_err());
+        assert!("newest:4294967295,random:1".parse::<Selection>().is_err());
+        assert!("".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn test_selection_display() {
+        let mixed = Selection::Mixed(vec![(Selection::Newest, 3), (Selection::Random, 1)]);
+
+        assert_eq!(Selection::Newest.to_string(), "newest");
+        assert_eq!(mixed.to_string(), "75% newest / 25% random");
+
+        let heavy = Selection::Mixed(vec![(Selection::Newest, u32::MAX), (Selection::Random, 1)]);
+        assert_eq!(heavy.to_string(), "99% newest / 0% random");
+    }
+}
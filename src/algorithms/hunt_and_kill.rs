@@ -1,10 +1,12 @@
-use super::On;
+use super::{events::Event, On};
 use crate::{
     grids::base_grid::BaseGrid,
     utils::random::{random_cell, random_neighbor},
 };
+use rand::RngCore;
 
 /// The `HuntAndKill` struct represents the Hunt and Kill algorithm for generating mazes.
+#[derive(Default)]
 pub struct HuntAndKill {}
 
 impl HuntAndKill {
@@ -19,10 +21,20 @@ impl On for HuntAndKill {
     /// # Arguments
     ///
     /// * `grid` - The grid on which to execute the algorithm.
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let mut current = Some(random_cell(grid));
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut current = Some(random_cell(grid, rng));
 
         while let Some(ref cell) = current {
+            let position = cell.borrow().to_row_and_column();
+            observer(Event::Visited(position));
+
             let neighbors = cell.borrow().neighbors();
 
             let unvisited = neighbors
@@ -35,10 +47,15 @@ impl On for HuntAndKill {
                 .collect::<Vec<(i32, i32)>>();
 
             if !unvisited.is_empty() {
-                let neighbor = random_neighbor(grid, &unvisited);
+                let neighbor = random_neighbor(grid, &unvisited, rng);
                 cell.borrow_mut().link(neighbor.clone());
+                observer(Event::Linked(
+                    position,
+                    neighbor.borrow().to_row_and_column(),
+                ));
                 current = Some(neighbor);
             } else {
+                observer(Event::HuntStarted(position));
                 current = None;
 
                 for cell in grid.iter() {
@@ -55,8 +72,12 @@ impl On for HuntAndKill {
 
                     if cell.borrow().links().is_empty() && !visited.is_empty() {
                         current = Some(cell.clone());
-                        let neighbor = random_neighbor(grid, &visited);
+                        let neighbor = random_neighbor(grid, &visited, rng);
                         cell.borrow_mut().link(neighbor.clone());
+                        observer(Event::Linked(
+                            cell.borrow().to_row_and_column(),
+                            neighbor.borrow().to_row_and_column(),
+                        ));
                         break;
                     }
                 }
@@ -75,11 +96,33 @@ mod tests {
         let mut grid = Grid::new(5, 5);
         let hunt_and_kill = HuntAndKill::new();
 
-        hunt_and_kill.on(&mut grid);
+        hunt_and_kill.on(&mut grid, &mut rand::thread_rng());
 
         // Assert that all cells are linked
         for cell in grid.iter() {
             assert!(!cell.borrow().links().is_empty());
         }
     }
+
+    #[test]
+    fn test_hunt_started_events() {
+        let mut grid = Grid::new(8, 8);
+        let events = HuntAndKill::new()
+            .events(&mut grid, &mut rand::thread_rng())
+            .collect::<Vec<Event>>();
+
+        let hunts = events
+            .iter()
+            .filter(|event| matches!(event, Event::HuntStarted(_)))
+            .count();
+        let links = events
+            .iter()
+            .filter(|event| matches!(event, Event::Linked(_, _)))
+            .count();
+
+        // Every walk but the first one starts with a hunt, and the last hunt finds nothing.
+        assert!(hunts >= 1);
+        assert_eq!(links, 8 * 8 - 1);
+        assert!(matches!(events.last(), Some(Event::HuntStarted(_))));
+    }
 }
@@ -0,0 +1,278 @@
+use super::{events::Event, On};
+use crate::{
+    grids::base_grid::{BaseGrid, WithRowsAndColumns},
+    utils::disjoint_set::DisjointSet,
+};
+use rand::{seq::SliceRandom, RngCore};
+
+/// A pair of neighboring cells, which can be linked by the Kruskal's algorithm.
+pub type Edge = ((i32, i32), (i32, i32));
+
+/// The state of the Kruskal's algorithm: the edges between neighboring cells and the sets of
+/// cells already connected to each other.
+///
+/// Cells can be merged before running the algorithm, to force a corridor into the maze, or
+/// opened into a room; the algorithm then fills in the rest of the spanning tree.
+#[derive(Debug, Clone)]
+pub struct State {
+    edges: Vec<Edge>,
+    sets: DisjointSet<(i32, i32)>,
+}
+
+impl State {
+    /// Creates the state of the given grid, where every cell is in a set of its own.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which the algorithm runs.
+    ///
+    /// # Returns
+    ///
+    /// A new `State` instance.
+    pub fn new(grid: &dyn WithRowsAndColumns) -> Self {
+        let mut edges = vec![];
+        let mut sets = DisjointSet::new();
+
+        for cell in grid.iter() {
+            let cell = cell.borrow();
+            let position = cell.to_row_and_column();
+
+            sets.insert(position);
+
+            for neighbor in cell.neighbors() {
+                if position < neighbor {
+                    edges.push((position, neighbor));
+                }
+            }
+        }
+
+        Self { edges, sets }
+    }
+
+    /// Returns the edges between neighboring cells.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the sets of cells connected to each other.
+    pub fn sets(&self) -> &DisjointSet<(i32, i32)> {
+        &self.sets
+    }
+
+    /// Returns whether two cells can be linked without creating a loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The first cell.
+    /// * `right` - The second cell.
+    pub fn can_merge(&self, left: (i32, i32), right: (i32, i32)) -> bool {
+        self.sets.contains(left) && self.sets.contains(right) && !self.sets.connected(left, right)
+    }
+
+    /// Links two cells and merges their sets, unless they are already connected.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid the cells belong to.
+    /// * `left` - The first cell.
+    /// * `right` - The second cell.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the cells have been linked.
+    pub fn merge(
+        &mut self,
+        grid: &dyn WithRowsAndColumns,
+        left: (i32, i32),
+        right: (i32, i32),
+    ) -> bool {
+        if !self.can_merge(left, right) {
+            return false;
+        }
+
+        let (Some(left_cell), Some(right_cell)) =
+            (grid.cell(left.0, left.1), grid.cell(right.0, right.1))
+        else {
+            return false;
+        };
+
+        left_cell.borrow_mut().link(right_cell.clone());
+        self.sets.union(left, right)
+    }
+
+    /// Opens the given cells into a room: every pair of neighbors among them is linked, even if
+    /// the cells are already connected, and their sets are merged. The room has loops, unlike
+    /// the rest of the maze.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid the cells belong to.
+    /// * `cells` - The cells of the room; the cells outside the grid are ignored.
+    pub fn add_room(&mut self, grid: &dyn WithRowsAndColumns, cells: &[(i32, i32)]) {
+        for &position in cells {
+            let Some(cell) = grid.cell(position.0, position.1) else {
+                continue;
+            };
+
+            let neighbors = cell.borrow().neighbors();
+
+            for neighbor in neighbors {
+                if position < neighbor && cells.contains(&neighbor) {
+                    cell.borrow_mut()
+                        .link(grid.cell(neighbor.0, neighbor.1).unwrap().clone());
+                    self.sets.union(position, neighbor);
+                }
+            }
+        }
+    }
+}
+
+/// The `Kruskals` struct represents the randomized Kruskal's algorithm.
+///
+/// Every pair of neighboring cells is an edge: the edges are shuffled and, one by one, the cells
+/// of an edge are linked if they are not already connected, until a single set remains.
+#[derive(Default)]
+pub struct Kruskals {}
+
+impl Kruskals {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Executes the Kruskal's algorithm on the given grid, starting from the given state.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `state` - The state of the algorithm, possibly with cells already merged.
+    /// * `rng` - The random number generator used to shuffle the edges.
+    pub fn on_state(&self, grid: &mut dyn BaseGrid, state: &mut State, rng: &mut dyn RngCore) {
+        self.carve(grid, state, rng, &mut |_| {});
+    }
+
+    /// Links the cells of the shuffled edges, reporting every passage to the observer.
+    fn carve(
+        &self,
+        grid: &mut dyn BaseGrid,
+        state: &mut State,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut edges = state.edges.clone();
+        edges.shuffle(rng);
+
+        for (left, right) in edges {
+            if state.merge(grid, left, right) {
+                observer(Event::Linked(left, right));
+            }
+        }
+    }
+}
+
+impl On for Kruskals {
+    /// Executes the Kruskal's algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut state = State::new(grid);
+
+        self.carve(grid, &mut state, rng, observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{assert_perfect, passages},
+        grids::grid::Grid,
+    };
+
+    #[test]
+    fn test_state() {
+        let grid = Grid::new(2, 3);
+        let mut state = State::new(&grid);
+
+        // Two horizontal edges on each row, three vertical edges.
+        assert_eq!(state.edges().len(), 7);
+        assert_eq!(state.sets().count(), 6);
+        assert!(state.can_merge((0, 0), (0, 1)));
+        assert!(state.merge(&grid, (0, 0), (0, 1)));
+        assert!(!state.merge(&grid, (0, 1), (0, 0)));
+        assert!(!state.can_merge((0, 0), (9, 9)));
+        assert_eq!(state.sets().count(), 5);
+        assert!(state.sets().connected((0, 0), (0, 1)));
+        assert_eq!(state.sets().size_of((0, 1)), 2);
+
+        let cell = grid.cell(0, 0).unwrap().borrow();
+        assert!(cell.links().contains_key(&(0, 1)));
+    }
+
+    #[test]
+    fn test_kruskals() {
+        let mut grid = Grid::new(5, 6);
+        let kruskals = Kruskals::new();
+
+        kruskals.on(&mut grid, &mut rand::thread_rng());
+
+        assert_perfect(&grid);
+    }
+
+    #[test]
+    fn test_kruskals_with_pre_linked_cells() {
+        let mut grid = Grid::new(5, 5);
+        let mut state = State::new(&grid);
+
+        // A corridor along the first row.
+        for column in 0..4 {
+            state.merge(&grid, (0, column), (0, column + 1));
+        }
+
+        Kruskals::new().on_state(&mut grid, &mut state, &mut rand::thread_rng());
+
+        assert_perfect(&grid);
+        assert_eq!(state.sets().count(), 1);
+
+        for column in 0..4 {
+            let cell = grid.cell(0, column).unwrap().borrow();
+            assert!(cell.links().contains_key(&(0, column + 1)));
+        }
+    }
+
+    #[test]
+    fn test_kruskals_with_a_room() {
+        let mut grid = Grid::new(5, 5);
+        let mut state = State::new(&grid);
+        let room = [(1, 1), (1, 2), (2, 1), (2, 2)];
+
+        state.add_room(&grid, &room);
+
+        assert_eq!(state.sets().count(), 5 * 5 - 3);
+        assert!(!state.can_merge((1, 1), (2, 2)));
+
+        Kruskals::new().on_state(&mut grid, &mut state, &mut rand::thread_rng());
+
+        // The spanning tree, and the fourth passage of the room which closes its loop.
+        assert_eq!(passages(&grid), (5 * 5 - 1 + 1, true));
+        assert_eq!(state.sets().count(), 1);
+
+        for (from, to) in [
+            ((1, 1), (1, 2)),
+            ((2, 1), (2, 2)),
+            ((1, 1), (2, 1)),
+            ((1, 2), (2, 2)),
+        ] {
+            let cell = grid.cell(from.0, from.1).unwrap().borrow();
+            assert!(cell.links().contains_key(&to));
+        }
+    }
+}
@@ -1,25 +1,139 @@
+use self::events::{Event, Events};
 use self::{
-    aldous_broder::AldousBroder, binary_tree::BinaryTree, hunt_and_kill::HuntAndKill,
-    recursive_backtracker::RecursiveBacktracker, sidewinder::Sidewinder, wilsons::Wilsons,
+    aldous_broder::AldousBroder, aldous_broder_wilsons::AldousBroderWilsons,
+    binary_tree::BinaryTree, ellers::Ellers, growing_tree::GrowingTree, hunt_and_kill::HuntAndKill,
+    kruskals::Kruskals, recursive_backtracker::RecursiveBacktracker,
+    recursive_division::RecursiveDivision, sidewinder::Sidewinder,
+    simplified_prims::SimplifiedPrims, true_prims::TruePrims, wilsons::Wilsons,
 };
 use crate::grids::base_grid::BaseGrid;
+use rand::RngCore;
 use std::fmt::Display;
 
 pub mod aldous_broder;
+pub mod aldous_broder_wilsons;
 pub mod binary_tree;
+pub mod ellers;
+pub mod events;
+pub mod growing_tree;
 pub mod hunt_and_kill;
+pub mod kruskals;
 pub mod recursive_backtracker;
+pub mod recursive_division;
 pub mod sidewinder;
+pub mod simplified_prims;
+pub mod true_prims;
 pub mod wilsons;
 
+/// The `On` trait is implemented by every maze generator.
+///
+/// The random number generator is injected so that a seeded generator (e.g. a `StdRng`
+/// built with `SeedableRng::seed_from_u64`) always carves the same maze on the same grid.
+///
+/// Every step of the generation is reported to an observer, as an `Event`, so that the carving
+/// of the maze can be shown frame by frame.
 pub trait On {
-    fn on(&self, grid: &mut dyn BaseGrid);
+    /// Generates the maze on the given grid, reporting every step of the generation to an
+    /// observer.
+    ///
+    /// The observer is called as the maze is carved, once for every event and in order:
+    /// `Visited` when the generator moves to a cell, `Linked` and `Unlinked` once the passage
+    /// was carved or the wall added, and `Backtracked` when the generator is done with a cell.
+    /// Each generator only reports the events that fit it, e.g. only the walks of Wilson's
+    /// algorithm have loops to erase. The observer must not borrow the cells of the grid, which the
+    /// generator may still hold.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The function called with every event.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    );
+
+    /// Generates the maze on the given grid, like `on_observed` with an observer that ignores
+    /// every event.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    fn on(&self, grid: &mut dyn BaseGrid, rng: &mut dyn RngCore) {
+        self.on_observed(grid, rng, &mut |_| {});
+    }
+
+    /// Generates the maze on the given grid, recording every step of the generation.
+    ///
+    /// The grid is fully carved when this returns: to show the generation, replay the events
+    /// on a fresh grid of the same size.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the events, in the order they happened.
+    fn events(&self, grid: &mut dyn BaseGrid, rng: &mut dyn RngCore) -> Events {
+        let mut events = vec![];
+        self.on_observed(grid, rng, &mut |event| events.push(event));
+
+        events.into_iter()
+    }
 }
 
+/// The `Apply` trait dispatches a maze generator chosen at runtime to its `On` implementation.
 pub trait Apply {
-    fn apply(&self, grid: &mut dyn BaseGrid);
+    /// Generates the maze on the given grid with the chosen generator, reporting every step of
+    /// the generation to an observer, as `On::on_observed` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The function called with every event.
+    fn apply_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    );
+
+    /// Generates the maze on the given grid with the chosen generator, like `apply_observed`
+    /// with an observer that ignores every event.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    fn apply(&self, grid: &mut dyn BaseGrid, rng: &mut dyn RngCore) {
+        self.apply_observed(grid, rng, &mut |_| {});
+    }
+
+    /// Generates the maze on the given grid, recording every step of the generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the events, in the order they happened.
+    fn events(&self, grid: &mut dyn BaseGrid, rng: &mut dyn RngCore) -> Events {
+        let mut events = vec![];
+        self.apply_observed(grid, rng, &mut |event| events.push(event));
+
+        events.into_iter()
+    }
 }
 
+/// The maze generators available in the crate.
 pub enum Algorithms {
     BinaryTree(BinaryTree),
     Sidewinder(Sidewinder),
@@ -27,17 +141,40 @@ pub enum Algorithms {
     Wilsons(Wilsons),
     HuntAndKill(HuntAndKill),
     RecursiveBacktracker(RecursiveBacktracker),
+    GrowingTree(GrowingTree),
+    Kruskals(Kruskals),
+    SimplifiedPrims(SimplifiedPrims),
+    TruePrims(TruePrims),
+    Ellers(Ellers),
+    RecursiveDivision(RecursiveDivision),
+    AldousBroderWilsons(AldousBroderWilsons),
 }
 
 impl Apply for Algorithms {
-    fn apply(&self, grid: &mut dyn BaseGrid) {
+    fn apply_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
         match self {
-            Algorithms::BinaryTree(algorithm) => algorithm.on(grid),
-            Algorithms::Sidewinder(algorithm) => algorithm.on(grid),
-            Algorithms::AldousBroder(algorithm) => algorithm.on(grid),
-            Algorithms::Wilsons(algorithm) => algorithm.on(grid),
-            Algorithms::HuntAndKill(algorithm) => algorithm.on(grid),
-            Algorithms::RecursiveBacktracker(algorithm) => algorithm.on(grid),
+            Algorithms::BinaryTree(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::Sidewinder(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::AldousBroder(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::Wilsons(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::HuntAndKill(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::RecursiveBacktracker(algorithm) => {
+                algorithm.on_observed(grid, rng, observer)
+            }
+            Algorithms::GrowingTree(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::Kruskals(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::SimplifiedPrims(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::TruePrims(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::Ellers(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::RecursiveDivision(algorithm) => algorithm.on_observed(grid, rng, observer),
+            Algorithms::AldousBroderWilsons(algorithm) => {
+                algorithm.on_observed(grid, rng, observer)
+            }
         }
     }
 }
@@ -51,6 +188,133 @@ impl Display for Algorithms {
             Algorithms::Wilsons(_) => write!(f, "Wilsons"),
             Algorithms::HuntAndKill(_) => write!(f, "Hunt And Kill"),
             Algorithms::RecursiveBacktracker(_) => write!(f, "Recursive Backtracker"),
+            Algorithms::GrowingTree(algorithm) => {
+                write!(f, "Growing Tree ({})", algorithm.selection())
+            }
+            Algorithms::Kruskals(_) => write!(f, "Kruskals"),
+            Algorithms::SimplifiedPrims(_) => write!(f, "Simplified Prims"),
+            Algorithms::TruePrims(_) => write!(f, "True Prims"),
+            Algorithms::Ellers(_) => write!(f, "Ellers"),
+            Algorithms::RecursiveDivision(_) => write!(f, "Recursive Division"),
+            Algorithms::AldousBroderWilsons(algorithm) => write!(
+                f,
+                "Aldous Broder + Wilsons ({}% Aldous Broder)",
+                (algorithm.fraction() * 100.0).round()
+            ),
+        }
+    }
+}
+
+/// Returns the number of passages of a maze and whether every cell can be reached from the north
+/// west cell.
+#[cfg(test)]
+pub(crate) fn passages(grid: &dyn crate::grids::base_grid::WithRowsAndColumns) -> (usize, bool) {
+    use crate::grids::distances::Distances;
+
+    let links: usize = grid.iter().map(|cell| cell.borrow().links().len()).sum();
+    let root = grid.cell(0, 0).unwrap().clone();
+    let mut distances = Distances::new((0, 0));
+    distances.calculate(root, grid);
+
+    let reachable = grid
+        .iter()
+        .all(|cell| distances.get(cell.borrow().to_row_and_column()).is_some());
+
+    (links / 2, reachable)
+}
+
+/// Asserts that a maze is perfect, a spanning tree of its grid: every cell can be reached, through
+/// one passage less than there are cells, so without loops.
+#[cfg(test)]
+pub(crate) fn assert_perfect(grid: &dyn crate::grids::base_grid::WithRowsAndColumns) {
+    let cells = (grid.rows() * grid.columns()) as usize;
+
+    assert_eq!(passages(grid), (cells - 1, true), "the maze is not perfect");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::growing_tree::Selection,
+        grids::{base_grid::WithRowsAndColumns, grid::Grid},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn algorithms() -> Vec<Algorithms> {
+        vec![
+            Algorithms::BinaryTree(BinaryTree::new()),
+            Algorithms::Sidewinder(Sidewinder::new()),
+            Algorithms::AldousBroder(AldousBroder::new()),
+            Algorithms::Wilsons(Wilsons::new()),
+            Algorithms::HuntAndKill(HuntAndKill::new()),
+            Algorithms::RecursiveBacktracker(RecursiveBacktracker::new()),
+            Algorithms::GrowingTree(GrowingTree::new(Selection::Mixed(vec![
+                (Selection::Newest, 75),
+                (Selection::Random, 25),
+            ]))),
+            Algorithms::Kruskals(Kruskals::new()),
+            Algorithms::SimplifiedPrims(SimplifiedPrims::new()),
+            Algorithms::TruePrims(TruePrims::new()),
+            Algorithms::Ellers(Ellers::new()),
+            Algorithms::RecursiveDivision(RecursiveDivision::new().with_room_size(4)),
+            Algorithms::AldousBroderWilsons(AldousBroderWilsons::new(0.5)),
+        ]
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_maze() {
+        for algorithm in algorithms() {
+            let mut first = Grid::new(8, 6);
+            let mut second = Grid::new(8, 6);
+
+            algorithm.apply(&mut first, &mut StdRng::seed_from_u64(42));
+            algorithm.apply(&mut second, &mut StdRng::seed_from_u64(42));
+
+            assert_eq!(first, second, "{algorithm} is not reproducible");
+        }
+    }
+
+    #[test]
+    fn test_different_seeds_generate_different_mazes() {
+        for algorithm in algorithms() {
+            let mut first = Grid::new(8, 6);
+            let mut second = Grid::new(8, 6);
+
+            algorithm.apply(&mut first, &mut StdRng::seed_from_u64(1));
+            algorithm.apply(&mut second, &mut StdRng::seed_from_u64(2));
+
+            assert_ne!(first, second, "{algorithm} ignores the seed");
+        }
+    }
+
+    #[test]
+    fn test_events_replay_the_maze() {
+        for algorithm in algorithms() {
+            let mut grid = Grid::new(8, 6);
+            let replay = Grid::new(8, 6);
+            let link = |from: (i32, i32), to: (i32, i32)| {
+                (
+                    replay.cell(from.0, from.1).unwrap(),
+                    replay.cell(to.0, to.1).unwrap().clone(),
+                )
+            };
+
+            for event in algorithm.events(&mut grid, &mut StdRng::seed_from_u64(42)) {
+                match event {
+                    Event::Linked(from, to) => {
+                        let (cell, other) = link(from, to);
+                        cell.borrow_mut().link(other);
+                    }
+                    Event::Unlinked(from, to) => {
+                        let (cell, other) = link(from, to);
+                        cell.borrow_mut().unlink(other);
+                    }
+                    _ => {}
+                }
+            }
+
+            assert_eq!(grid, replay, "{algorithm} events do not replay the maze");
         }
     }
 }
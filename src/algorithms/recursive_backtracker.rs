@@ -1,10 +1,12 @@
-use super::On;
+use super::{events::Event, On};
 use crate::{
     grids::base_grid::{BaseGrid, GridCell},
     utils::random::{random_cell, random_neighbor},
 };
+use rand::RngCore;
 
 /// The `RecursiveBacktracker` struct represents the recursive backtracking algorithm.
+#[derive(Default)]
 pub struct RecursiveBacktracker {}
 
 impl RecursiveBacktracker {
@@ -19,8 +21,16 @@ impl On for RecursiveBacktracker {
     /// # Arguments
     ///
     /// * `grid` - The grid on which to execute the algorithm.
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let cell = random_cell(grid);
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let cell = random_cell(grid, rng);
+        observer(Event::Visited(cell.borrow().to_row_and_column()));
 
         let mut stack: Vec<GridCell> = vec![];
         stack.push(cell);
@@ -38,11 +48,18 @@ impl On for RecursiveBacktracker {
                 })
                 .collect::<Vec<(i32, i32)>>();
 
+            let position = current.borrow().to_row_and_column();
+
             if neighbors.is_empty() {
                 stack.pop();
+                observer(Event::Backtracked(position));
             } else {
-                let neighbor = random_neighbor(grid, &neighbors);
+                let neighbor = random_neighbor(grid, &neighbors, rng);
                 current.borrow_mut().link(neighbor.clone());
+
+                let neighbor_position = neighbor.borrow().to_row_and_column();
+                observer(Event::Linked(position, neighbor_position));
+                observer(Event::Visited(neighbor_position));
                 stack.push(neighbor);
             }
         }
@@ -60,11 +77,32 @@ mod tests {
         let mut grid = crate::grids::grid::Grid::new(5, 5);
         let recursive_backtracker = RecursiveBacktracker::new();
 
-        recursive_backtracker.on(&mut grid);
+        recursive_backtracker.on(&mut grid, &mut rand::thread_rng());
 
         // Assert that all cells are linked
         for cell in grid.iter() {
             assert!(!cell.borrow().links().is_empty());
         }
     }
+
+    #[test]
+    fn test_backtracked_events() {
+        let mut grid = crate::grids::grid::Grid::new(5, 5);
+        let events = RecursiveBacktracker::new()
+            .events(&mut grid, &mut rand::thread_rng())
+            .collect::<Vec<Event>>();
+
+        let visited = events
+            .iter()
+            .filter(|event| matches!(event, Event::Visited(_)))
+            .count();
+        let backtracked = events
+            .iter()
+            .filter(|event| matches!(event, Event::Backtracked(_)))
+            .count();
+
+        // Every cell is pushed on the stack once, and popped once.
+        assert_eq!(visited, 5 * 5);
+        assert_eq!(backtracked, 5 * 5);
+    }
 }
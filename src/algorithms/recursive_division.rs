@@ -0,0 +1,191 @@
+use super::{events::Event, On};
+use crate::grids::base_grid::{BaseGrid, WithRowsAndColumns};
+use rand::{Rng, RngCore};
+
+/// The `RecursiveDivision` struct represents the Recursive Division algorithm.
+///
+/// Unlike the other generators, which carve passages in a grid full of walls, the algorithm
+/// starts from a grid without walls: it divides the grid with a wall that has a single passage,
+/// then recursively divides both halves, until the regions are a single cell wide.
+///
+/// Regions smaller than the room size, in both dimensions, are not divided further and are
+/// left as open rooms.
+#[derive(Default)]
+pub struct RecursiveDivision {
+    room_size: i32,
+}
+
+impl RecursiveDivision {
+    pub fn new() -> Self {
+        Self { room_size: 0 }
+    }
+
+    /// Leaves the regions smaller than the given size as open rooms.
+    ///
+    /// # Arguments
+    ///
+    /// * `room_size` - The size below which a region is not divided any further; with a size of
+    ///   `2` or less every region is divided, and the maze has no rooms.
+    ///
+    /// # Returns
+    ///
+    /// The `RecursiveDivision` instance with the room size.
+    pub fn with_room_size(mut self, room_size: i32) -> Self {
+        self.room_size = room_size;
+        self
+    }
+
+    /// Returns the size below which a region is left as an open room.
+    pub fn room_size(&self) -> i32 {
+        self.room_size
+    }
+
+    /// Divides the given region of the grid, then both of its halves.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid being divided.
+    /// * `region` - The north west cell, the height and the width of the region.
+    /// * `rng` - The random number generator used to place the walls and their passages.
+    /// * `observer` - The observer notified of every wall added.
+    fn divide(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        region: (i32, i32, i32, i32),
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let (row, column, height, width) = region;
+
+        if height <= 1 || width <= 1 || (height < self.room_size && width < self.room_size) {
+            return;
+        }
+
+        let horizontally = match height.cmp(&width) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => rng.gen_bool(0.5),
+        };
+
+        if horizontally {
+            let divide_south_of = rng.gen_range(0..height - 1);
+            let passage_at = rng.gen_range(0..width);
+
+            for offset in (0..width).filter(|offset| *offset != passage_at) {
+                let cell = grid.cell(row + divide_south_of, column + offset).unwrap();
+                let south = grid
+                    .cell(row + divide_south_of + 1, column + offset)
+                    .unwrap();
+                cell.borrow_mut().unlink(south.clone());
+                observer(Event::Unlinked(
+                    cell.borrow().to_row_and_column(),
+                    south.borrow().to_row_and_column(),
+                ));
+            }
+
+            let north_height = divide_south_of + 1;
+            self.divide(grid, (row, column, north_height, width), rng, observer);
+            self.divide(
+                grid,
+                (row + north_height, column, height - north_height, width),
+                rng,
+                observer,
+            );
+        } else {
+            let divide_east_of = rng.gen_range(0..width - 1);
+            let passage_at = rng.gen_range(0..height);
+
+            for offset in (0..height).filter(|offset| *offset != passage_at) {
+                let cell = grid.cell(row + offset, column + divide_east_of).unwrap();
+                let east = grid
+                    .cell(row + offset, column + divide_east_of + 1)
+                    .unwrap();
+                cell.borrow_mut().unlink(east.clone());
+                observer(Event::Unlinked(
+                    cell.borrow().to_row_and_column(),
+                    east.borrow().to_row_and_column(),
+                ));
+            }
+
+            let west_width = divide_east_of + 1;
+            self.divide(grid, (row, column, height, west_width), rng, observer);
+            self.divide(
+                grid,
+                (row, column + west_width, height, width - west_width),
+                rng,
+                observer,
+            );
+        }
+    }
+}
+
+impl On for RecursiveDivision {
+    /// Executes the Recursive Division algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        for cell in grid.iter() {
+            let mut cell = cell.borrow_mut();
+            let position = cell.to_row_and_column();
+
+            for (row, column) in [cell.south(), cell.east()].into_iter().flatten() {
+                cell.link(grid.cell(row, column).unwrap().clone());
+                observer(Event::Linked(position, (row, column)));
+            }
+        }
+
+        let region = (0, 0, grid.rows(), grid.columns());
+        self.divide(grid, region, rng, observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{assert_perfect, passages},
+        grids::grid::Grid,
+    };
+
+    #[test]
+    fn test_recursive_division() {
+        let mut grid = Grid::new(7, 9);
+        let recursive_division = RecursiveDivision::new();
+
+        recursive_division.on(&mut grid, &mut rand::thread_rng());
+
+        // Without rooms the maze is perfect.
+        assert_perfect(&grid);
+    }
+
+    #[test]
+    fn test_recursive_division_with_rooms() {
+        let mut grid = Grid::new(4, 4);
+        let recursive_division = RecursiveDivision::new().with_room_size(5);
+
+        recursive_division.on(&mut grid, &mut rand::thread_rng());
+
+        // The whole grid is smaller than a room, so no wall is added.
+        assert_eq!(recursive_division.room_size(), 5);
+        assert_eq!(passages(&grid), (2 * 4 * 3, true));
+
+        let mut grid = Grid::new(12, 12);
+        RecursiveDivision::new()
+            .with_room_size(4)
+            .on(&mut grid, &mut rand::thread_rng());
+
+        let (links, reachable) = passages(&grid);
+
+        assert!(reachable);
+        assert!(links >= 12 * 12 - 1);
+    }
+}
@@ -1,6 +1,6 @@
-use super::On;
+use super::{events::Event, On};
 use crate::grids::base_grid::BaseGrid;
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 /// Implements the Sidewinder algorithm for generating mazes.
 ///
@@ -13,12 +13,13 @@ use rand::Rng;
 /// # Example
 ///
 /// ```
-/// use mazes::algorithms::Sidewinder;
-/// use mazes::grid::Grid;
+/// use mazes::algorithms::{sidewinder::Sidewinder, On};
+/// use mazes::Grid;
 ///
 /// let mut grid = Grid::new(10, 10);
-/// Sidewinder::on(&mut grid);
+/// Sidewinder::new().on(&mut grid, &mut rand::thread_rng());
 /// ```
+#[derive(Default)]
 pub struct Sidewinder {}
 
 impl Sidewinder {
@@ -28,14 +29,25 @@ impl Sidewinder {
 }
 
 impl On for Sidewinder {
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let mut rng = rand::thread_rng();
-
+    /// Executes the Sidewinder algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
         for row in grid.each_row() {
             let mut run = vec![];
 
             for cell in row {
                 run.push(cell);
+                observer(Event::Visited(cell.borrow().to_row_and_column()));
 
                 let should_close = {
                     let cell = cell.borrow_mut();
@@ -52,16 +64,45 @@ impl On for Sidewinder {
                     if let Some((northern_row, norther_column)) = member.north() {
                         let north = grid.cell(northern_row, norther_column).unwrap().to_owned();
                         member.link(north);
+                        observer(Event::Linked(
+                            member.to_row_and_column(),
+                            (northern_row, norther_column),
+                        ));
                     }
+
+                    // The next cell starts a new run: a run is only ever linked north once.
+                    drop(member);
+                    run.clear();
                 } else {
                     let mut cell = cell.borrow_mut();
 
                     if let Some((eastern_row, eastern_column)) = cell.east() {
                         let east = grid.cell(eastern_row, eastern_column).unwrap().to_owned();
                         cell.link(east);
+                        observer(Event::Linked(
+                            cell.to_row_and_column(),
+                            (eastern_row, eastern_column),
+                        ));
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::assert_perfect, grids::grid::Grid};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_sidewinder_generates_a_perfect_maze() {
+        for seed in 0..10 {
+            let mut grid = Grid::new(8, 12);
+            Sidewinder::new().on(&mut grid, &mut StdRng::seed_from_u64(seed));
+
+            assert_perfect(&grid);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::{events::Event, On};
+use crate::{
+    grids::base_grid::{BaseGrid, GridCell},
+    utils::random::{random_cell, random_neighbor},
+};
+use rand::{Rng, RngCore};
+
+/// The `SimplifiedPrims` struct represents the simplified Prim's algorithm.
+///
+/// The algorithm grows the maze from a random cell, keeping a list of active cells: at every
+/// step a random active cell is linked to a random unvisited neighbor, which becomes active.
+/// The mazes have a radial texture, with many short dead ends.
+#[derive(Default)]
+pub struct SimplifiedPrims {}
+
+impl SimplifiedPrims {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl On for SimplifiedPrims {
+    /// Executes the simplified Prim's algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut active: Vec<GridCell> = vec![random_cell(grid, rng)];
+        observer(Event::Visited(active[0].borrow().to_row_and_column()));
+
+        while !active.is_empty() {
+            let index = rng.gen_range(0..active.len());
+            let current = &active[index];
+
+            let neighbors = current
+                .borrow()
+                .neighbors()
+                .into_iter()
+                .filter(|(row, column)| {
+                    let neighbor = grid.cell(*row, *column).unwrap();
+                    neighbor.borrow().links().is_empty()
+                })
+                .collect::<Vec<(i32, i32)>>();
+
+            let position = current.borrow().to_row_and_column();
+
+            if neighbors.is_empty() {
+                active.swap_remove(index);
+                observer(Event::Backtracked(position));
+            } else {
+                let neighbor = random_neighbor(grid, &neighbors, rng);
+                current.borrow_mut().link(neighbor.clone());
+
+                let neighbor_position = neighbor.borrow().to_row_and_column();
+                observer(Event::Linked(position, neighbor_position));
+                observer(Event::Visited(neighbor_position));
+                active.push(neighbor);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorithms::assert_perfect, grids::grid::Grid};
+
+    #[test]
+    fn test_simplified_prims() {
+        let mut grid = Grid::new(5, 7);
+        let simplified_prims = SimplifiedPrims::new();
+
+        simplified_prims.on(&mut grid, &mut rand::thread_rng());
+
+        assert_perfect(&grid);
+    }
+}
@@ -0,0 +1,153 @@
+use super::{events::Event, On};
+use crate::{grids::base_grid::BaseGrid, utils::random::random_cell};
+use rand::{Rng, RngCore};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// The highest cost randomly assigned to a cell.
+pub const MAX_COST: u32 = 100;
+
+/// The `TruePrims` struct represents the true Prim's algorithm.
+///
+/// Every cell is given a random cost; the active cells are kept in a priority queue, and at every
+/// step the cheapest active cell is linked to its cheapest unvisited neighbor, which becomes
+/// active. The mazes have a radial texture, with many short dead ends.
+#[derive(Default)]
+pub struct TruePrims {}
+
+impl TruePrims {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Executes the true Prim's algorithm on the given grid, with the given cost of every cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `costs` - The cost of every cell; cells without a cost are the most expensive.
+    /// * `rng` - The random number generator used to choose the starting cell.
+    pub fn on_costs(
+        &self,
+        grid: &mut dyn BaseGrid,
+        costs: &HashMap<(i32, i32), u32>,
+        rng: &mut dyn RngCore,
+    ) {
+        self.carve(grid, costs, rng, &mut |_| {});
+    }
+
+    /// Grows the maze from a random cell, reporting every step to the observer.
+    fn carve(
+        &self,
+        grid: &mut dyn BaseGrid,
+        costs: &HashMap<(i32, i32), u32>,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let cost = |cell: (i32, i32)| costs.get(&cell).copied().unwrap_or(u32::MAX);
+
+        let start = random_cell(grid, rng).borrow().to_row_and_column();
+        let mut active = BinaryHeap::new();
+        active.push(Reverse((cost(start), start)));
+        observer(Event::Visited(start));
+
+        while let Some(Reverse((_, position))) = active.peek().copied() {
+            let current = grid.cell(position.0, position.1).unwrap();
+
+            let neighbor = current
+                .borrow()
+                .neighbors()
+                .into_iter()
+                .filter(|(row, column)| {
+                    let neighbor = grid.cell(*row, *column).unwrap();
+                    neighbor.borrow().links().is_empty()
+                })
+                .min_by_key(|neighbor| (cost(*neighbor), *neighbor));
+
+            match neighbor {
+                Some(neighbor) => {
+                    let cell = grid.cell(neighbor.0, neighbor.1).unwrap().clone();
+                    current.borrow_mut().link(cell);
+                    observer(Event::Linked(position, neighbor));
+                    observer(Event::Visited(neighbor));
+                    active.push(Reverse((cost(neighbor), neighbor)));
+                }
+                None => {
+                    active.pop();
+                    observer(Event::Backtracked(position));
+                }
+            }
+        }
+    }
+}
+
+impl On for TruePrims {
+    /// Executes the true Prim's algorithm on the given grid, with random costs.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to execute the algorithm.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let costs = grid
+            .iter()
+            .map(|cell| {
+                (
+                    cell.borrow().to_row_and_column(),
+                    rng.gen_range(0..MAX_COST),
+                )
+            })
+            .collect::<HashMap<(i32, i32), u32>>();
+
+        self.carve(grid, &costs, rng, observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::assert_perfect,
+        grids::{base_grid::WithRowsAndColumns, grid::Grid},
+    };
+
+    #[test]
+    fn test_true_prims() {
+        let mut grid = Grid::new(5, 7);
+        let true_prims = TruePrims::new();
+
+        true_prims.on(&mut grid, &mut rand::thread_rng());
+
+        assert_perfect(&grid);
+    }
+
+    #[test]
+    fn test_true_prims_follows_the_cheapest_cells() {
+        let mut grid = Grid::new(2, 3);
+
+        // The first row is cheap, the second one is expensive: the first row is carved as a
+        // single corridor, whatever the starting cell.
+        let costs = grid
+            .iter()
+            .map(|cell| {
+                let (row, column) = cell.borrow().to_row_and_column();
+                ((row, column), if row == 0 { 1 } else { 50 + column as u32 })
+            })
+            .collect::<HashMap<(i32, i32), u32>>();
+
+        TruePrims::new().on_costs(&mut grid, &costs, &mut rand::thread_rng());
+
+        for column in 0..2 {
+            let cell = grid.cell(0, column).unwrap().borrow();
+            assert!(cell.links().contains_key(&(0, column + 1)));
+        }
+    }
+}
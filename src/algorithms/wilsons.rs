@@ -1,82 +1,140 @@
-use super::On;
+use super::{events::Event, On};
 use crate::{
-    grids::base_grid::BaseGrid,
+    grids::base_grid::{BaseGrid, GridCell},
     utils::random::{random_cell, random_neighbor},
 };
-use rand::Rng;
+use rand::{Rng, RngCore};
+use std::collections::HashMap;
 
 /// The Wilsons struct represents the Wilson's algorithm for generating mazes.
+#[derive(Default)]
 pub struct Wilsons {}
 
 impl Wilsons {
     pub fn new() -> Self {
         Self {}
     }
-}
 
-impl On for Wilsons {
-    /// Generates a maze using the Wilson's algorithm on the given grid.
+    /// Executes the Wilson's algorithm on the given grid, where some cells may already be part
+    /// of the maze.
+    ///
+    /// Random walks start from random cells and go on until they reach a visited cell; the loops
+    /// are erased along the way, then the walk is carved into the maze.
     ///
     /// # Arguments
     ///
     /// * `grid` - The grid on which to generate the maze.
-    fn on(&self, grid: &mut dyn BaseGrid) {
-        let mut unvisited = vec![];
-
-        for cell in grid.iter() {
-            unvisited.push(cell);
-        }
-
-        let mut rng = rand::thread_rng();
-        let first = rng.gen_range(0..unvisited.len());
-        unvisited.remove(first);
-
-        while !unvisited.is_empty() {
-            let mut path = vec![];
-
-            let mut cell = random_cell(grid);
-            path.push(cell.clone());
-
-            while unvisited.contains(&&cell) {
-                cell = random_neighbor(grid, &cell.clone().borrow().neighbors());
-
-                let position = path.iter().position(|c| {
-                    c.borrow().to_row_and_column() == cell.borrow().to_row_and_column()
-                });
-
-                if let Some(position) = position {
-                    path.truncate(position + 1);
+    /// * `visited` - Whether every cell, in row-major order, is already part of the maze; at
+    ///   least one cell must be visited. All the cells are visited when the algorithm ends.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    pub fn on_visited(
+        &self,
+        grid: &mut dyn BaseGrid,
+        visited: &mut [bool],
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let index = |cell: &GridCell| {
+            let (row, column) = cell.borrow().to_row_and_column();
+            (row * grid.columns() + column) as usize
+        };
+
+        let mut unvisited = visited.iter().filter(|visited| !**visited).count();
+
+        while unvisited > 0 {
+            let mut cell = random_cell(grid, rng);
+            let mut path = vec![cell.clone()];
+            let mut positions = HashMap::from([(index(&cell), 0)]);
+            observer(Event::Visited(cell.borrow().to_row_and_column()));
+
+            while !visited[index(&cell)] {
+                cell = random_neighbor(grid, &cell.clone().borrow().neighbors(), rng);
+                observer(Event::Visited(cell.borrow().to_row_and_column()));
+
+                if let Some(position) = positions.get(&index(&cell)).copied() {
+                    let mut erased = vec![];
+
+                    for cell in path.drain(position + 1..) {
+                        positions.remove(&index(&cell));
+                        erased.push(cell.borrow().to_row_and_column());
+                    }
+
+                    observer(Event::WalkErased(erased));
                 } else {
+                    positions.insert(index(&cell), path.len());
                     path.push(cell.clone());
                 }
             }
 
-            let mut visited = vec![];
-            for index in 0..path.len() - 1 {
-                path[index].borrow_mut().link(path[index + 1].clone());
-                visited.push(path[index].clone());
+            for pair in path.windows(2) {
+                pair[0].borrow_mut().link(pair[1].clone());
+                observer(Event::Linked(
+                    pair[0].borrow().to_row_and_column(),
+                    pair[1].borrow().to_row_and_column(),
+                ));
+                visited[index(&pair[0])] = true;
+                unvisited -= 1;
             }
-
-            unvisited.retain(|cell| !visited.contains(cell));
         }
     }
 }
 
+impl On for Wilsons {
+    /// Generates a maze using the Wilson's algorithm on the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which to generate the maze.
+    /// * `rng` - The random number generator used to carve the maze.
+    /// * `observer` - The observer notified of every step of the generation.
+    fn on_observed(
+        &self,
+        grid: &mut dyn BaseGrid,
+        rng: &mut dyn RngCore,
+        observer: &mut dyn FnMut(Event),
+    ) {
+        let mut visited = vec![false; (grid.rows() * grid.columns()) as usize];
+
+        let first = rng.gen_range(0..visited.len());
+        visited[first] = true;
+
+        self.on_visited(grid, &mut visited, rng, observer);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::grids::{base_grid::WithRowsAndColumns, grid::Grid};
+    use rand::SeedableRng;
 
     #[test]
     fn test_wilsons_on() {
         let mut grid = Grid::new(3, 3);
         let wilsons = Wilsons::new();
 
-        wilsons.on(&mut grid);
+        wilsons.on(&mut grid, &mut rand::thread_rng());
 
         // Assert that all cells are linked
         for cell in grid.iter() {
             assert!(!cell.borrow().links().is_empty());
         }
     }
+
+    #[test]
+    fn test_walk_erased_events() {
+        let mut grid = Grid::new(10, 10);
+        let events = Wilsons::new()
+            .events(&mut grid, &mut rand::rngs::StdRng::seed_from_u64(5))
+            .collect::<Vec<Event>>();
+
+        let erased = events.iter().find_map(|event| match event {
+            Event::WalkErased(cells) => Some(cells),
+            _ => None,
+        });
+
+        // On a 10x10 grid the first random walk almost surely loops.
+        assert!(erased.is_some_and(|cells| !cells.is_empty()));
+    }
 }
@@ -0,0 +1,314 @@
+use crate::grids::{base_grid::WithRowsAndColumns, cell::Cell, distances::Distances};
+use std::{iter::Sum, ops::Add};
+
+/// The structure of one or more mazes: how many dead ends, junctions, corridors and turns they
+/// have, how long their longest path and their straight corridors are, and whether their
+/// passages run mostly horizontally or vertically.
+///
+/// The statistics of several mazes are added together, e.g. with `Iterator::sum`, and every
+/// accessor returns the average over the mazes.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Statistics {
+    mazes: usize,
+    cells: usize,
+    dead_ends: usize,
+    junctions: usize,
+    corridors: usize,
+    turns: usize,
+    longest_path: usize,
+    runs: usize,
+    max_run: usize,
+    horizontal_passages: usize,
+    vertical_passages: usize,
+}
+
+impl Statistics {
+    /// Analyzes the structure of a maze.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The generated maze.
+    ///
+    /// # Returns
+    ///
+    /// The `Statistics` of the maze.
+    pub fn new(grid: &(impl WithRowsAndColumns + ?Sized)) -> Self {
+        let mut statistics = Self {
+            mazes: 1,
+            longest_path: Distances::longest_path(grid).length() as usize,
+            ..Self::default()
+        };
+
+        for cell in grid.iter() {
+            let cell = cell.borrow();
+            let links = cell.links();
+            let linked = |neighbor: Option<(i32, i32)>| {
+                neighbor.is_some_and(|neighbor| links.contains_key(&neighbor))
+            };
+
+            statistics.cells += 1;
+
+            match links.len() {
+                1 => statistics.dead_ends += 1,
+                2 if linked(cell.north()) && linked(cell.south())
+                    || linked(cell.east()) && linked(cell.west()) =>
+                {
+                    statistics.corridors += 1
+                }
+                2 => statistics.turns += 1,
+                0 => {}
+                _ => statistics.junctions += 1,
+            }
+
+            if linked(cell.east()) {
+                statistics.horizontal_passages += 1;
+            }
+
+            if linked(cell.south()) {
+                statistics.vertical_passages += 1;
+            }
+        }
+
+        let horizontal = (0..grid.rows()).map(|row| {
+            runs(
+                grid,
+                (0..grid.columns()).map(|column| (row, column)),
+                Cell::east,
+            )
+        });
+        let vertical = (0..grid.columns())
+            .map(|column| runs(grid, (0..grid.rows()).map(|row| (row, column)), Cell::south));
+
+        for (count, longest) in horizontal.chain(vertical) {
+            statistics.runs += count;
+            statistics.max_run = statistics.max_run.max(longest);
+        }
+
+        statistics
+    }
+
+    /// Returns the number of analyzed mazes.
+    pub fn mazes(&self) -> usize {
+        self.mazes
+    }
+
+    /// Returns the average number of cells with a single passage.
+    pub fn dead_ends(&self) -> f64 {
+        self.average(self.dead_ends)
+    }
+
+    /// Returns the fraction of the cells, between `0` and `1`, that are dead ends.
+    pub fn dead_end_ratio(&self) -> f64 {
+        ratio(self.dead_ends, self.cells)
+    }
+
+    /// Returns the average number of cells with three or four passages.
+    pub fn junctions(&self) -> f64 {
+        self.average(self.junctions)
+    }
+
+    /// Returns the average number of cells with two opposite passages.
+    pub fn corridors(&self) -> f64 {
+        self.average(self.corridors)
+    }
+
+    /// Returns the average number of cells with two passages at a right angle.
+    pub fn turns(&self) -> f64 {
+        self.average(self.turns)
+    }
+
+    /// Returns the average length, in passages, of the longest path between two cells.
+    pub fn longest_path(&self) -> f64 {
+        self.average(self.longest_path)
+    }
+
+    /// Returns the average length, in passages, of the straight corridor runs: the sequences
+    /// of passages in the same direction.
+    pub fn average_run(&self) -> f64 {
+        ratio(self.horizontal_passages + self.vertical_passages, self.runs)
+    }
+
+    /// Returns the average length, in passages, of the longest straight corridor run.
+    pub fn max_run(&self) -> f64 {
+        self.average(self.max_run)
+    }
+
+    /// Returns the fraction of the passages, between `0` and `1`, that run east-west; `0.5`
+    /// means the maze has no horizontal or vertical bias.
+    pub fn horizontal_bias(&self) -> f64 {
+        ratio(
+            self.horizontal_passages,
+            self.horizontal_passages + self.vertical_passages,
+        )
+    }
+
+    /// Returns the average of a total over the analyzed mazes.
+    fn average(&self, total: usize) -> f64 {
+        ratio(total, self.mazes)
+    }
+}
+
+impl Add for Statistics {
+    type Output = Self;
+
+    /// Adds the statistics of two sets of mazes together.
+    fn add(self, other: Self) -> Self {
+        Self {
+            mazes: self.mazes + other.mazes,
+            cells: self.cells + other.cells,
+            dead_ends: self.dead_ends + other.dead_ends,
+            junctions: self.junctions + other.junctions,
+            corridors: self.corridors + other.corridors,
+            turns: self.turns + other.turns,
+            longest_path: self.longest_path + other.longest_path,
+            runs: self.runs + other.runs,
+            max_run: self.max_run + other.max_run,
+            horizontal_passages: self.horizontal_passages + other.horizontal_passages,
+            vertical_passages: self.vertical_passages + other.vertical_passages,
+        }
+    }
+}
+
+impl Sum for Statistics {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Returns the ratio of two counts, or `0` if there is nothing to divide by.
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+/// Counts the straight corridor runs along a line of cells, and measures the longest one.
+///
+/// # Arguments
+///
+/// * `grid` - The generated maze.
+/// * `line` - The cells of a row, from west to east, or of a column, from north to south.
+/// * `next` - Returns the position of the next cell of the line, after the given one.
+///
+/// # Returns
+///
+/// The number of runs and the length, in passages, of the longest one.
+fn runs(
+    grid: &(impl WithRowsAndColumns + ?Sized),
+    line: impl Iterator<Item = (i32, i32)>,
+    next: impl Fn(&Cell) -> Option<(i32, i32)>,
+) -> (usize, usize) {
+    let mut count = 0;
+    let mut longest = 0;
+    let mut length = 0;
+
+    for (row, column) in line {
+        let linked = grid.cell(row, column).is_some_and(|cell| {
+            let cell = cell.borrow();
+            next(&cell).is_some_and(|next| cell.links().contains_key(&next))
+        });
+
+        if linked {
+            length += 1;
+        } else if length > 0 {
+            count += 1;
+            longest = longest.max(length);
+            length = 0;
+        }
+    }
+
+    (count, longest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{binary_tree::BinaryTree, recursive_backtracker::RecursiveBacktracker, On},
+        grids::{grid::Grid, test_support::link},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_statistics() {
+        // +---+---+---+
+        // |           |
+        // +---+   +---+
+        // |       |   |
+        // +   +---+   +
+        // |           |
+        // +---+---+---+
+        let grid = Grid::new(3, 3);
+
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (0, 2));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+        link(&grid, (1, 0), (2, 0));
+        link(&grid, (2, 0), (2, 1));
+        link(&grid, (2, 1), (2, 2));
+        link(&grid, (2, 2), (1, 2));
+
+        let statistics = Statistics::new(&grid);
+
+        assert_eq!(statistics.mazes(), 1);
+        assert_eq!(statistics.dead_ends(), 3.0);
+        assert_eq!(statistics.dead_end_ratio(), 3.0 / 9.0);
+        assert_eq!(statistics.junctions(), 1.0);
+        assert_eq!(statistics.corridors(), 1.0);
+        assert_eq!(statistics.turns(), 4.0);
+        // From the north west (or north east) corner to the east cell of the middle row.
+        assert_eq!(statistics.longest_path(), 7.0);
+        // A run along every row and every column: 8 passages in 6 runs.
+        assert_eq!(statistics.average_run(), 8.0 / 6.0);
+        assert_eq!(statistics.max_run(), 2.0);
+        assert_eq!(statistics.horizontal_bias(), 5.0 / 8.0);
+    }
+
+    #[test]
+    fn test_perfect_maze() {
+        let mut grid = Grid::new(10, 10);
+        RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(1));
+
+        let statistics = Statistics::new(&grid);
+        let cells = statistics.dead_ends()
+            + statistics.junctions()
+            + statistics.corridors()
+            + statistics.turns();
+
+        assert_eq!(cells, 100.0);
+        assert!(statistics.longest_path() >= 18.0);
+    }
+
+    #[test]
+    fn test_binary_tree_bias() {
+        let mut grid = Grid::new(10, 10);
+        BinaryTree::new().on(&mut grid, &mut StdRng::seed_from_u64(2));
+
+        let statistics = Statistics::new(&grid);
+
+        // The north row and the east column are a single corridor.
+        assert_eq!(statistics.max_run(), 9.0);
+    }
+
+    #[test]
+    fn test_average() {
+        let empty = Grid::new(3, 3);
+        let maze = Grid::new(3, 3);
+        link(&maze, (0, 0), (0, 1));
+
+        let statistics = [&empty, &maze]
+            .into_iter()
+            .map(Statistics::new)
+            .sum::<Statistics>();
+
+        assert_eq!(statistics.mazes(), 2);
+        assert_eq!(statistics.dead_ends(), 1.0);
+        assert_eq!(statistics.dead_end_ratio(), 2.0 / 18.0);
+        assert_eq!(statistics.longest_path(), 0.5);
+        assert_eq!(statistics.horizontal_bias(), 1.0);
+        assert_eq!(Statistics::default().dead_end_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,394 @@
+use colored::Colorize;
+use crossterm::{
+    cursor,
+    event::{self, Event as TerminalEvent, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    terminal::{self, ClearType},
+    QueueableCommand,
+};
+use mazes::{Event, Grid, GridCell, GridDisplay, Style, WithRowsAndColumns};
+use std::{
+    collections::HashSet,
+    io::{self, IsTerminal, Write},
+    time::{Duration, Instant},
+};
+
+/// The highest number of frames drawn per second; faster speeds apply several steps per frame.
+const MAX_FRAME_RATE: u32 = 60;
+
+/// The slowest and the fastest speeds, in steps per second.
+const MIN_SPEED: u32 = 1;
+const MAX_SPEED: u32 = 10_000;
+
+/// The colors of the highlighted cells.
+const CURRENT_COLOR: [u8; 3] = [220, 50, 47];
+const ACTIVE_COLOR: [u8; 3] = [181, 137, 0];
+const WALK_COLOR: [u8; 3] = [38, 139, 210];
+
+/// Replays the events of a maze generation on a fresh grid, keeping track of the cells worth
+/// highlighting.
+pub struct Playback {
+    grid: Grid,
+    events: Vec<Event>,
+    position: usize,
+    current: Option<(i32, i32)>,
+    active: Vec<(i32, i32)>,
+    walk: Vec<(i32, i32)>,
+    tracks_active: bool,
+}
+
+impl Playback {
+    /// Creates a new playback of the given events.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows of the grid the events were recorded on.
+    /// * `columns` - The number of columns of the grid the events were recorded on.
+    /// * `events` - The events of the generation.
+    ///
+    /// # Returns
+    ///
+    /// A new `Playback` instance, before the first event.
+    pub fn new(rows: i32, columns: i32, events: Vec<Event>) -> Self {
+        // Only the generators that backtrack, like the Recursive Backtracker and Prim's, keep
+        // a stack or a set of active cells.
+        let tracks_active = events
+            .iter()
+            .any(|event| matches!(event, Event::Backtracked(_)));
+
+        Self {
+            grid: Grid::new(rows, columns),
+            events,
+            position: 0,
+            current: None,
+            active: vec![],
+            walk: vec![],
+            tracks_active,
+        }
+    }
+
+    /// Returns whether every event has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.position == self.events.len()
+    }
+
+    /// Returns the number of replayed events, and the number of events.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.position, self.events.len())
+    }
+
+    /// Replays the next event.
+    ///
+    /// # Returns
+    ///
+    /// `false` if every event has already been replayed.
+    pub fn step(&mut self) -> bool {
+        let Some(event) = self.events.get(self.position).cloned() else {
+            return false;
+        };
+
+        self.position += 1;
+
+        match event {
+            Event::Visited(position) => {
+                self.current = Some(position);
+
+                if self.tracks_active && !self.active.contains(&position) {
+                    self.active.push(position);
+                }
+
+                if self.is_unlinked(position) && !self.walk.contains(&position) {
+                    self.walk.push(position);
+                }
+            }
+            Event::Linked(from, to) => {
+                let (cell, other) = self.cells(from, to);
+                cell.borrow_mut().link(other);
+                self.walk
+                    .retain(|position| *position != from && *position != to);
+            }
+            Event::Unlinked(from, to) => {
+                let (cell, other) = self.cells(from, to);
+                cell.borrow_mut().unlink(other);
+                self.current = Some(from);
+            }
+            Event::Backtracked(position) => {
+                self.active.retain(|active| *active != position);
+                self.current = self.active.last().copied();
+            }
+            Event::WalkErased(cells) => {
+                self.walk.retain(|position| !cells.contains(position));
+            }
+            Event::HuntStarted(_) => {
+                self.current = None;
+            }
+        }
+
+        if self.is_finished() {
+            self.current = None;
+            self.active.clear();
+            self.walk.clear();
+        }
+
+        true
+    }
+
+    /// Draws the grid as it is, with the highlighted cells in color.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The characters used to draw the walls.
+    ///
+    /// # Returns
+    ///
+    /// The drawn grid.
+    pub fn frame(&self, style: Style) -> String {
+        let current = self.current;
+        let active = self.active.iter().copied().collect::<HashSet<(i32, i32)>>();
+        let walk = self.walk.iter().copied().collect::<HashSet<(i32, i32)>>();
+
+        GridDisplay::new(
+            &self.grid,
+            Box::new(move |cell| {
+                let position = cell.borrow().to_row_and_column();
+
+                let color = if current == Some(position) {
+                    Some(CURRENT_COLOR)
+                } else if walk.contains(&position) {
+                    Some(WALK_COLOR)
+                } else if active.contains(&position) {
+                    Some(ACTIVE_COLOR)
+                } else {
+                    None
+                };
+
+                match color {
+                    Some([red, green, blue]) => "   ".on_truecolor(red, green, blue).to_string(),
+                    None => String::from("   "),
+                }
+            }),
+        )
+        .with_style(style)
+        .to_string()
+    }
+
+    /// Returns whether the cell at the given position has no passage yet.
+    fn is_unlinked(&self, (row, column): (i32, i32)) -> bool {
+        self.grid
+            .cell(row, column)
+            .is_some_and(|cell| cell.borrow().links().is_empty())
+    }
+
+    /// Returns the cells at the given positions of the grid.
+    fn cells(&self, from: (i32, i32), to: (i32, i32)) -> (&GridCell, GridCell) {
+        (
+            self.grid.cell(from.0, from.1).unwrap(),
+            self.grid.cell(to.0, to.1).unwrap().clone(),
+        )
+    }
+}
+
+/// Restores the terminal when the animation ends, even on errors.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        io::stdout().queue(cursor::Hide)?.flush()?;
+
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = io::stdout()
+            .queue(cursor::Show)
+            .and_then(|stdout| stdout.flush());
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Plays the generation of a maze in the terminal, redrawing the grid in place.
+///
+/// The keys control the playback: `space` pauses and resumes it, `n` or `→` replay a single
+/// step while paused, `+` and `-` double or halve the speed, and `q` or `esc` skip to the end.
+///
+/// # Arguments
+///
+/// * `playback` - The playback of the generation.
+/// * `style` - The characters used to draw the walls.
+/// * `speed` - The initial number of steps per second.
+///
+/// # Returns
+///
+/// An error if the standard output is not a terminal, or cannot be written to.
+pub fn play(mut playback: Playback, style: Style, speed: u32) -> io::Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(io::Error::other(
+            "the animation must be played in a terminal",
+        ));
+    }
+
+    let _raw_mode = RawMode::enable()?;
+    let mut stdout = io::stdout().lock();
+    let mut speed = speed.clamp(MIN_SPEED, MAX_SPEED);
+    let mut paused = false;
+
+    stdout.queue(terminal::Clear(ClearType::All))?;
+
+    loop {
+        let frame_started = Instant::now();
+        draw(&mut stdout, &playback, style, speed, paused)?;
+
+        if playback.is_finished() {
+            break;
+        }
+
+        let frame_rate = speed.min(MAX_FRAME_RATE);
+        let frame = Duration::from_secs(1) / frame_rate;
+        let mut steps = if paused {
+            0
+        } else {
+            speed.div_ceil(frame_rate)
+        };
+
+        while let Some(remaining) = frame.checked_sub(frame_started.elapsed()) {
+            if !event::poll(remaining)? {
+                break;
+            }
+
+            let TerminalEvent::Key(KeyEvent {
+                code,
+                modifiers,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            else {
+                continue;
+            };
+
+            match code {
+                KeyCode::Char(' ') => {
+                    paused = !paused;
+                    steps = 0;
+                }
+                KeyCode::Char('n') | KeyCode::Right if paused => steps += 1,
+                KeyCode::Char('+') | KeyCode::Char('=') => speed = (speed * 2).min(MAX_SPEED),
+                KeyCode::Char('-') => speed = (speed / 2).max(MIN_SPEED),
+                KeyCode::Char('q') | KeyCode::Esc => while playback.step() {},
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    while playback.step() {}
+                }
+                _ => {}
+            }
+        }
+
+        for _ in 0..steps {
+            playback.step();
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws a frame of the playback, over the previous one, followed by a status line.
+fn draw(
+    stdout: &mut impl Write,
+    playback: &Playback,
+    style: Style,
+    speed: u32,
+    paused: bool,
+) -> io::Result<()> {
+    let (position, total) = playback.progress();
+    let state = if playback.is_finished() {
+        "done"
+    } else if paused {
+        "paused"
+    } else {
+        "playing"
+    };
+    let status = format!(
+        "step {position}/{total} · {speed} steps/s · {state} · \
+         space: pause  n: step  +/-: speed  q: skip"
+    );
+
+    stdout.queue(cursor::MoveTo(0, 0))?;
+
+    // In raw mode a new line does not return the cursor to the first column.
+    for line in playback.frame(style).lines() {
+        write!(stdout, "{line}\r\n")?;
+    }
+
+    stdout.queue(terminal::Clear(ClearType::CurrentLine))?;
+    write!(stdout, "{status}\r\n")?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mazes::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, wilsons::Wilsons, On},
+        WithDisplay,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_playback_rebuilds_the_maze() {
+        let mut grid = Grid::new(4, 5);
+        let events = RecursiveBacktracker::new()
+            .events(&mut grid, &mut StdRng::seed_from_u64(1))
+            .collect::<Vec<Event>>();
+
+        let mut playback = Playback::new(4, 5, events);
+
+        assert_eq!(playback.progress().0, 0);
+
+        while playback.step() {}
+
+        assert!(playback.is_finished());
+        assert_eq!(playback.grid, grid);
+        assert_eq!(playback.frame(Style::Ascii), grid.display().to_string());
+    }
+
+    #[test]
+    fn test_playback_tracks_the_stack() {
+        let mut grid = Grid::new(4, 4);
+        let events = RecursiveBacktracker::new()
+            .events(&mut grid, &mut StdRng::seed_from_u64(2))
+            .collect::<Vec<Event>>();
+
+        let mut playback = Playback::new(4, 4, events);
+        let mut deepest = 0;
+
+        // The current cell is always the top of the stack.
+        while playback.step() && !playback.is_finished() {
+            assert_eq!(playback.active.last(), playback.current.as_ref());
+            // Only the starting cell is briefly part of a walk, before its first passage.
+            assert!(playback.walk.len() <= 1);
+            deepest = deepest.max(playback.active.len());
+        }
+
+        assert!(deepest > 1);
+        assert!(playback.active.is_empty());
+    }
+
+    #[test]
+    fn test_playback_tracks_the_walk() {
+        let mut grid = Grid::new(6, 6);
+        let events = Wilsons::new()
+            .events(&mut grid, &mut StdRng::seed_from_u64(3))
+            .collect::<Vec<Event>>();
+
+        let mut playback = Playback::new(6, 6, events);
+        let mut longest = 0;
+
+        while playback.step() {
+            longest = longest.max(playback.walk.len());
+            assert!(playback.active.is_empty());
+        }
+
+        assert!(longest > 1);
+        assert!(playback.walk.is_empty());
+    }
+}
@@ -0,0 +1,888 @@
+use crate::{Options, MAX_GRID_SIZE, MIN_GRID_SIZE};
+use clap::{Parser, ValueEnum};
+use mazes::{
+    algorithms::{
+        aldous_broder::AldousBroder,
+        aldous_broder_wilsons::AldousBroderWilsons,
+        binary_tree::BinaryTree,
+        ellers::Ellers,
+        growing_tree::{GrowingTree, Selection},
+        hunt_and_kill::HuntAndKill,
+        kruskals::Kruskals,
+        recursive_backtracker::RecursiveBacktracker,
+        recursive_division::RecursiveDivision,
+        sidewinder::Sidewinder,
+        simplified_prims::SimplifiedPrims,
+        true_prims::TruePrims,
+        wilsons::Wilsons,
+    },
+    Algorithms, AnimatedRenderer, AnimationFormat, DeadEndFilling, Hand, Heuristic, RasterRenderer,
+    Root, Solvers, Style, SvgRenderer, Tremaux, WallFollower,
+};
+use std::path::PathBuf;
+
+/// Generates a maze without any interactive prompt.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+pub struct Cli {
+    /// The algorithm used to generate the maze.
+    #[arg(short, long, value_enum, default_value_t = Algorithm::RecursiveBacktracker)]
+    algorithm: Algorithm,
+
+    /// How the Growing Tree algorithm selects the next active cell: `newest`, `oldest`,
+    /// `random`, or a weighted mix like `newest:75,random:25`.
+    #[arg(long, default_value = "newest:75,random:25")]
+    selection: Selection,
+
+    /// The size below which the Recursive Division algorithm leaves a region as an open room;
+    /// with `0` the maze has no rooms.
+    #[arg(long, default_value_t = 0)]
+    room_size: i32,
+
+    /// The fraction of the cells, between `0` and `1`, visited by the Aldous-Broder algorithm
+    /// before the hybrid generator switches to the Wilson's algorithm.
+    #[arg(long, default_value_t = 0.5, value_parser = fraction)]
+    switch_at: f64,
+
+    /// The probability, between `0` and `1`, that a dead end is removed by linking it to a
+    /// neighbor, which adds loops to the maze.
+    #[arg(long, default_value_t = 0.0, value_parser = fraction)]
+    braid: f64,
+
+    /// The number of rows of the grid.
+    #[arg(short, long, default_value_t = 10, value_parser = grid_size)]
+    rows: i32,
+
+    /// The number of columns of the grid; defaults to the number of rows.
+    #[arg(short, long, value_parser = grid_size)]
+    columns: Option<i32>,
+
+    /// The seed of the random number generator; a random one is used when missing.
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Show the distance of every cell from the root, the north west cell by default.
+    #[arg(short, long, group = "measured")]
+    distances: bool,
+
+    /// Show the longest path of the maze, between the two cells farthest apart, instead of the
+    /// distances from the root; the solution follows it too.
+    #[arg(long, group = "measured", conflicts_with_all = ["root", "goal"])]
+    longest_path: bool,
+
+    /// The cell the distances and the solution start from: `ROW,COLUMN`, `center`, `random`,
+    /// or `farthest:` followed by another cell, e.g. `farthest:center`.
+    #[arg(long, value_parser = root, default_value = "0,0")]
+    root: Root,
+
+    /// The cell the solution ends at, like the root; the south east cell by default. With
+    /// `--distances`, a text maze shows the solution instead of every distance.
+    #[arg(long, value_parser = root)]
+    goal: Option<Root>,
+
+    /// Give every cell a random cost, from 1 to the given one, and show the cost and the
+    /// cheapest distance of every cell of a text maze, as `cost:distance`; braid the maze to
+    /// give the costs more than one way to go.
+    #[arg(long, value_name = "MAX_COST", group = "measured", conflicts_with_all = ["format", "longest_path"], value_parser = clap::value_parser!(i32).range(1..=99))]
+    weights: Option<i32>,
+
+    /// Solve the maze from the root to the goal with an A* search led by the given heuristic,
+    /// `manhattan` by default, and show the path and the other cells the search expanded; with
+    /// `zero`, the search expands as many cells as a breadth-first search.
+    #[arg(long, value_name = "HEURISTIC", value_enum, num_args = 0..=1, default_missing_value = "manhattan", group = "measured", conflicts_with_all = ["format", "longest_path", "weights", "animate"])]
+    a_star: Option<SearchHeuristic>,
+
+    /// Mark the ends of the longest path with `s`, the start, and `g`, the goal.
+    #[arg(long, requires = "longest_path")]
+    markers: bool,
+
+    /// Show the distances with colors.
+    #[arg(long, requires = "measured")]
+    colors: bool,
+
+    /// Draw the solution from the root to the goal in an SVG image, or trace it at the end of
+    /// an animated image.
+    #[arg(long)]
+    solution: bool,
+
+    /// Solve the maze from the root to the goal like a person in the maze would: an animated
+    /// image shows every move of the solver before the path it found, and the other formats
+    /// report its moves.
+    #[arg(long, value_enum, requires = "solution")]
+    solver: Option<Solver>,
+
+    /// The characters used to draw the walls of a text maze.
+    #[arg(long, value_enum, default_value_t = TextStyle::Ascii)]
+    style: TextStyle,
+
+    /// The format of the maze; in images and compact text the distances are rendered as colors.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// The size, in pixels, of the side of a cell of an image.
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..=100))]
+    cell_size: u32,
+
+    /// The thickness, in pixels, of the walls of an image.
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..=20))]
+    wall_thickness: u32,
+
+    /// The empty space, in pixels, around the maze in an image.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(..=100))]
+    margin: u32,
+
+    /// How long, in milliseconds, every frame of an animated image is shown.
+    #[arg(long, default_value_t = 40, value_parser = clap::value_parser!(u16).range(10..))]
+    frame_delay: u16,
+
+    /// How many steps of the generation every frame of an animated image shows.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    steps_per_frame: u32,
+
+    /// Write the maze to the given file instead of the standard output.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Play the generation of the maze in the terminal: `space` pauses, `n` steps while paused,
+    /// `+` and `-` change the speed, `q` skips to the end.
+    #[arg(long, conflicts_with_all = ["format", "output", "stream"])]
+    animate: bool,
+
+    /// The initial speed of the animation, in steps per second.
+    #[arg(long, default_value_t = 30, requires = "animate", value_parser = clap::value_parser!(u32).range(1..=10_000))]
+    speed: u32,
+
+    /// Print an Eller's maze as plain text, row by row as soon as every row is generated,
+    /// without keeping the maze in memory, to the standard output; it never ends unless a number
+    /// of rows is given.
+    #[arg(long, value_name = "ROWS", num_args = 0..=1, value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["algorithm", "rows", "braid", "measured", "root", "goal", "solution", "style", "format", "output"])]
+    stream: Option<Option<u64>>,
+
+    /// Print the structure of every algorithm's mazes, dead ends, junctions, corridors, longest
+    /// path and passage bias, averaged over the given number of mazes per algorithm.
+    #[arg(long, value_name = "MAZES", num_args = 0..=1, default_missing_value = "100", value_parser = clap::value_parser!(u64).range(1..), conflicts_with_all = ["animate", "stream", "format"])]
+    stats: Option<u64>,
+}
+
+/// The formats the maze can be written in.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// Plain text, drawn with the characters of the chosen style.
+    Text,
+    /// Compact text, drawn with quadrant block characters in a single character per cell.
+    Compact,
+    /// A PNG image.
+    Png,
+    /// An SVG image, with the distances and the solution in separate layers.
+    Svg,
+    /// An animated GIF image of the generation; the distances show the breadth-first search
+    /// from the north west cell.
+    Gif,
+    /// An animated PNG image of the generation, like the GIF image but without its 256 colors
+    /// per frame.
+    Apng,
+}
+
+impl Format {
+    /// Returns the format of the animated image, if the maze is written as one.
+    pub fn animation(self) -> Option<AnimationFormat> {
+        match self {
+            Format::Gif => Some(AnimationFormat::Gif),
+            Format::Apng => Some(AnimationFormat::Apng),
+            Format::Text | Format::Compact | Format::Png | Format::Svg => None,
+        }
+    }
+}
+
+/// The styles of the walls of a text maze.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum TextStyle {
+    /// The `+---+` and `|` ASCII characters.
+    Ascii,
+    /// Light box-drawing characters.
+    Light,
+    /// Heavy box-drawing characters.
+    Heavy,
+    /// Light box-drawing characters with rounded corners.
+    Rounded,
+}
+
+impl From<TextStyle> for Style {
+    fn from(style: TextStyle) -> Self {
+        match style {
+            TextStyle::Ascii => Style::Ascii,
+            TextStyle::Light => Style::Light,
+            TextStyle::Heavy => Style::Heavy,
+            TextStyle::Rounded => Style::Rounded,
+        }
+    }
+}
+
+/// The heuristics of the A* search.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum SearchHeuristic {
+    /// The number of rows plus the number of columns to the goal.
+    Manhattan,
+    /// The straight line distance to the goal.
+    Euclidean,
+    /// No estimate, like a breadth-first search.
+    Zero,
+}
+
+impl From<SearchHeuristic> for Heuristic {
+    fn from(heuristic: SearchHeuristic) -> Self {
+        match heuristic {
+            SearchHeuristic::Manhattan => Heuristic::Manhattan,
+            SearchHeuristic::Euclidean => Heuristic::Euclidean,
+            SearchHeuristic::Zero => Heuristic::Zero,
+        }
+    }
+}
+
+/// The solvers that can be chosen from the command line.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Solver {
+    /// Keep the left hand on the wall.
+    WallFollower,
+    /// Keep the right hand on the wall.
+    RightWallFollower,
+    /// Mark the passages, and never cross one marked twice.
+    Tremaux,
+    /// Fill the dead ends until only the solution is left.
+    DeadEndFilling,
+}
+
+impl From<Solver> for Solvers {
+    fn from(solver: Solver) -> Self {
+        match solver {
+            Solver::WallFollower => Solvers::WallFollower(WallFollower::new()),
+            Solver::RightWallFollower => {
+                Solvers::WallFollower(WallFollower::new().with_hand(Hand::Right))
+            }
+            Solver::Tremaux => Solvers::Tremaux(Tremaux::new()),
+            Solver::DeadEndFilling => Solvers::DeadEndFilling(DeadEndFilling::new()),
+        }
+    }
+}
+
+/// The algorithms that can be chosen from the command line.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum Algorithm {
+    BinaryTree,
+    Sidewinder,
+    AldousBroder,
+    Wilsons,
+    HuntAndKill,
+    RecursiveBacktracker,
+    GrowingTree,
+    Kruskals,
+    SimplifiedPrims,
+    TruePrims,
+    Ellers,
+    RecursiveDivision,
+    AldousBroderWilsons,
+}
+
+impl Algorithm {
+    /// Creates the maze generator of the algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - The selection strategy used by the Growing Tree algorithm.
+    /// * `room_size` - The size of the rooms left by the Recursive Division algorithm.
+    /// * `switch_at` - The fraction of the cells visited by the Aldous-Broder algorithm before
+    ///   the hybrid generator switches to the Wilson's algorithm.
+    ///
+    /// # Returns
+    ///
+    /// The chosen `Algorithms` variant.
+    fn generator(self, selection: Selection, room_size: i32, switch_at: f64) -> Algorithms {
+        match self {
+            Algorithm::BinaryTree => Algorithms::BinaryTree(BinaryTree::new()),
+            Algorithm::Sidewinder => Algorithms::Sidewinder(Sidewinder::new()),
+            Algorithm::AldousBroder => Algorithms::AldousBroder(AldousBroder::new()),
+            Algorithm::Wilsons => Algorithms::Wilsons(Wilsons::new()),
+            Algorithm::HuntAndKill => Algorithms::HuntAndKill(HuntAndKill::new()),
+            Algorithm::RecursiveBacktracker => {
+                Algorithms::RecursiveBacktracker(RecursiveBacktracker::new())
+            }
+            Algorithm::GrowingTree => Algorithms::GrowingTree(GrowingTree::new(selection)),
+            Algorithm::Kruskals => Algorithms::Kruskals(Kruskals::new()),
+            Algorithm::SimplifiedPrims => Algorithms::SimplifiedPrims(SimplifiedPrims::new()),
+            Algorithm::TruePrims => Algorithms::TruePrims(TruePrims::new()),
+            Algorithm::Ellers => Algorithms::Ellers(Ellers::new()),
+            Algorithm::RecursiveDivision => {
+                Algorithms::RecursiveDivision(RecursiveDivision::new().with_room_size(room_size))
+            }
+            Algorithm::AldousBroderWilsons => {
+                Algorithms::AldousBroderWilsons(AldousBroderWilsons::new(switch_at))
+            }
+        }
+    }
+}
+
+impl Cli {
+    /// Converts the parsed arguments into the `Options` used to generate the maze.
+    ///
+    /// # Returns
+    ///
+    /// The `Options` described by the command line arguments.
+    pub fn into_options(self) -> Options {
+        let rows = self.rows;
+        let columns = self.columns.unwrap_or(rows);
+        let seed = self.seed.unwrap_or_else(rand::random);
+
+        Options {
+            algorithm: self.algorithm.generator(
+                self.selection.clone(),
+                self.room_size,
+                self.switch_at,
+            ),
+            braid: self.braid,
+            rows,
+            columns,
+            seed,
+            with_distance: self.distances,
+            with_colors: self.colors,
+            with_solution: self.solution,
+            solver: self.solver.map(Solvers::from),
+            longest_path: self.longest_path,
+            weights: self.weights,
+            a_star: self.a_star.map(Heuristic::from),
+            root: seeded(self.root, seed),
+            goal: seeded(
+                self.goal.unwrap_or(Root::Cell((rows - 1, columns - 1))),
+                seed.wrapping_add(1),
+            ),
+            markers: self.markers,
+            style: self.style.into(),
+            format: self.format,
+            raster: RasterRenderer::new()
+                .with_cell_size(self.cell_size)
+                .with_wall_thickness(self.wall_thickness)
+                .with_margin(self.margin),
+            svg: SvgRenderer::new()
+                .with_cell_size(self.cell_size)
+                .with_wall_thickness(self.wall_thickness)
+                .with_margin(self.margin),
+            animation: AnimatedRenderer::new()
+                .with_raster(
+                    RasterRenderer::new()
+                        .with_cell_size(self.cell_size)
+                        .with_wall_thickness(self.wall_thickness)
+                        .with_margin(self.margin),
+                )
+                .with_frame_delay(self.frame_delay)
+                .with_steps_per_frame(self.steps_per_frame as usize),
+            output: self.output,
+            animate: self.animate,
+            speed: self.speed,
+            stream: self.stream,
+            stats: self.stats.map(|mazes| mazes as usize),
+            compared: if self.stats.is_some() {
+                Algorithm::value_variants()
+                    .iter()
+                    .map(|algorithm| {
+                        algorithm.generator(self.selection.clone(), self.room_size, self.switch_at)
+                    })
+                    .collect()
+            } else {
+                vec![]
+            },
+        }
+    }
+}
+
+/// Parses and validates the size of the grid.
+///
+/// # Arguments
+///
+/// * `value` - The raw command line value.
+///
+/// # Returns
+///
+/// The size of the grid, or an error message if the value is not a number in the allowed range.
+fn grid_size(value: &str) -> Result<i32, String> {
+    let size: i32 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+
+    if !(MIN_GRID_SIZE..=MAX_GRID_SIZE).contains(&size) {
+        return Err(format!(
+            "the size must be between {MIN_GRID_SIZE} and {MAX_GRID_SIZE}"
+        ));
+    }
+
+    Ok(size)
+}
+
+/// Parses a cell of the maze.
+///
+/// # Arguments
+///
+/// * `value` - The raw command line value.
+///
+/// # Returns
+///
+/// The `Root` selecting the cell, or an error message if the value is not a cell; a random
+/// cell is seeded later, with the seed of the maze.
+fn root(value: &str) -> Result<Root, String> {
+    match value {
+        "center" => Ok(Root::Center),
+        "random" => Ok(Root::Random(0)),
+        _ => {
+            if let Some(other) = value.strip_prefix("farthest:") {
+                return Ok(Root::FarthestFrom(Box::new(root(other)?)));
+            }
+
+            let (row, column) = value
+                .split_once(',')
+                .and_then(|(row, column)| {
+                    Some((row.trim().parse().ok()?, column.trim().parse().ok()?))
+                })
+                .ok_or_else(|| {
+                    format!("`{value}` is not `ROW,COLUMN`, `center`, `random` or `farthest:CELL`")
+                })?;
+
+            Ok(Root::Cell((row, column)))
+        }
+    }
+}
+
+/// Seeds the random cells of a root with the given seed, so that the same maze has the same
+/// random cells.
+fn seeded(root: Root, seed: u64) -> Root {
+    match root {
+        Root::Random(_) => Root::Random(seed),
+        Root::FarthestFrom(other) => Root::FarthestFrom(Box::new(seeded(*other, seed))),
+        root => root,
+    }
+}
+
+/// Parses and validates a fraction.
+///
+/// # Arguments
+///
+/// * `value` - The raw command line value.
+///
+/// # Returns
+///
+/// The fraction, or an error message if the value is not a number between `0` and `1`.
+fn fraction(value: &str) -> Result<f64, String> {
+    let fraction: f64 = value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))?;
+
+    if !(0.0..=1.0).contains(&fraction) {
+        return Err(String::from("the fraction must be between 0 and 1"));
+    }
+
+    Ok(fraction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let options = Cli::try_parse_from(["mazes", "--seed", "7"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.algorithm.to_string(), "Recursive Backtracker");
+        assert_eq!(options.rows, 10);
+        assert_eq!(options.columns, 10);
+        assert_eq!(options.seed, 7);
+        assert!(!options.with_distance);
+        assert!(!options.with_colors);
+        assert_eq!(options.format, Format::Text);
+        assert_eq!(options.raster, RasterRenderer::new());
+        assert_eq!(options.svg, SvgRenderer::new());
+        assert!(!options.with_solution);
+        assert_eq!(options.style, Style::Ascii);
+        assert!(options.output.is_none());
+        assert!(options.stream.is_none());
+        assert!(!options.animate);
+    }
+
+    #[test]
+    fn test_all_arguments() {
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--algorithm",
+            "hunt-and-kill",
+            "--rows",
+            "6",
+            "--columns",
+            "8",
+            "--distances",
+            "--colors",
+            "--output",
+            "maze.txt",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(options.algorithm.to_string(), "Hunt And Kill");
+        assert_eq!(options.rows, 6);
+        assert_eq!(options.columns, 8);
+        assert!(options.with_distance);
+        assert!(options.with_colors);
+        assert_eq!(options.output, Some(PathBuf::from("maze.txt")));
+    }
+
+    #[test]
+    fn test_png_arguments() {
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--format",
+            "png",
+            "--cell-size",
+            "8",
+            "--wall-thickness",
+            "3",
+            "--margin",
+            "0",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(options.format, Format::Png);
+        assert_eq!(
+            options.raster,
+            RasterRenderer::new()
+                .with_cell_size(8)
+                .with_wall_thickness(3)
+                .with_margin(0)
+        );
+    }
+
+    #[test]
+    fn test_style_argument() {
+        let options = Cli::try_parse_from(["mazes", "--style", "rounded"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.style, Style::Rounded);
+    }
+
+    #[test]
+    fn test_compact_format() {
+        let options = Cli::try_parse_from(["mazes", "--format", "compact", "--rows", "150"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.format, Format::Compact);
+        assert_eq!(options.columns, 150);
+    }
+
+    #[test]
+    fn test_svg_arguments() {
+        let options = Cli::try_parse_from(["mazes", "--format", "svg", "--solution"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.format, Format::Svg);
+        assert!(options.with_solution);
+    }
+
+    #[test]
+    fn test_animated_arguments() {
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--format",
+            "gif",
+            "--cell-size",
+            "6",
+            "--frame-delay",
+            "100",
+            "--steps-per-frame",
+            "5",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(options.format, Format::Gif);
+        assert_eq!(
+            options.animation,
+            AnimatedRenderer::new()
+                .with_raster(RasterRenderer::new().with_cell_size(6))
+                .with_frame_delay(100)
+                .with_steps_per_frame(5)
+        );
+
+        let options = Cli::try_parse_from(["mazes", "--format", "apng"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.format, Format::Apng);
+        assert_eq!(options.animation, AnimatedRenderer::new());
+
+        assert!(Cli::try_parse_from(["mazes", "--frame-delay", "5"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--steps-per-frame", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_growing_tree_selection() {
+        let options = Cli::try_parse_from(["mazes", "--algorithm", "growing-tree"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(
+            options.algorithm.to_string(),
+            "Growing Tree (75% newest / 25% random)"
+        );
+
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--algorithm",
+            "growing-tree",
+            "--selection",
+            "oldest",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(options.algorithm.to_string(), "Growing Tree (oldest)");
+    }
+
+    #[test]
+    fn test_room_size() {
+        let cli = Cli::try_parse_from([
+            "mazes",
+            "--algorithm",
+            "recursive-division",
+            "--room-size",
+            "5",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.room_size, 5);
+        assert_eq!(
+            cli.into_options().algorithm.to_string(),
+            "Recursive Division"
+        );
+    }
+
+    #[test]
+    fn test_switch_at() {
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--algorithm",
+            "aldous-broder-wilsons",
+            "--switch-at",
+            "0.25",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(
+            options.algorithm.to_string(),
+            "Aldous Broder + Wilsons (25% Aldous Broder)"
+        );
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let options = Cli::try_parse_from(["mazes", "--longest-path", "--markers", "--colors"])
+            .unwrap()
+            .into_options();
+
+        assert!(options.longest_path);
+        assert!(options.markers);
+        assert!(options.with_colors);
+        assert!(!options.with_distance);
+
+        assert!(Cli::try_parse_from(["mazes", "--markers"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--longest-path", "--distances"]).is_err());
+    }
+
+    #[test]
+    fn test_root_and_goal() {
+        let options = Cli::try_parse_from(["mazes", "--seed", "3", "--rows", "6"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.root, Root::Cell((0, 0)));
+        assert_eq!(options.goal, Root::Cell((5, 5)));
+
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--seed",
+            "3",
+            "--root",
+            "random",
+            "--goal",
+            "farthest:random",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(options.root, Root::Random(3));
+        assert_eq!(options.goal, Root::FarthestFrom(Box::new(Root::Random(4))));
+
+        let options = Cli::try_parse_from(["mazes", "--root", "center", "--goal", " 2, 7"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.root, Root::Center);
+        assert_eq!(options.goal, Root::Cell((2, 7)));
+
+        assert!(Cli::try_parse_from(["mazes", "--root", "middle"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--goal", "farthest:"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--longest-path", "--root", "center"]).is_err());
+    }
+
+    #[test]
+    fn test_weights() {
+        let options = Cli::try_parse_from(["mazes", "--weights", "9", "--colors"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.weights, Some(9));
+        assert!(options.with_colors);
+
+        assert!(Cli::try_parse_from(["mazes", "--weights", "0"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--weights", "9", "--format", "svg"]).is_err());
+    }
+
+    #[test]
+    fn test_a_star() {
+        let options = Cli::try_parse_from(["mazes", "--a-star", "--goal", "3,4"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.a_star, Some(Heuristic::Manhattan));
+        assert_eq!(options.goal, Root::Cell((3, 4)));
+
+        let options = Cli::try_parse_from(["mazes", "--a-star", "zero", "--colors"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.a_star, Some(Heuristic::Zero));
+        assert!(options.with_colors);
+
+        assert!(Cli::try_parse_from(["mazes", "--a-star", "--distances"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--a-star", "--format", "png"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--a-star", "--animate"]).is_err());
+    }
+
+    #[test]
+    fn test_solver() {
+        let options = Cli::try_parse_from([
+            "mazes",
+            "--solution",
+            "--solver",
+            "right-wall-follower",
+            "--format",
+            "gif",
+        ])
+        .unwrap()
+        .into_options();
+
+        assert_eq!(
+            options.solver,
+            Some(Solvers::WallFollower(
+                WallFollower::new().with_hand(Hand::Right)
+            ))
+        );
+
+        assert!(Cli::try_parse_from(["mazes", "--solver", "tremaux"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--solution", "--solver", "random"]).is_err());
+    }
+
+    #[test]
+    fn test_braid() {
+        let options = Cli::try_parse_from(["mazes", "--braid", "0.75"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.braid, 0.75);
+
+        assert!(Cli::try_parse_from(["mazes", "--braid", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_animate_arguments() {
+        let options = Cli::try_parse_from(["mazes", "--animate", "--speed", "120"])
+            .unwrap()
+            .into_options();
+
+        assert!(options.animate);
+        assert_eq!(options.speed, 120);
+
+        assert!(Cli::try_parse_from(["mazes", "--speed", "120"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--animate", "--format", "png"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--animate", "--speed", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_stream_argument() {
+        let options = Cli::try_parse_from(["mazes", "--stream", "--columns", "40"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.stream, Some(None));
+        assert_eq!(options.columns, 40);
+
+        let options = Cli::try_parse_from(["mazes", "--stream", "1000"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.stream, Some(Some(1000)));
+
+        // The stream is always an Eller's maze, printed as plain text to the standard output.
+        for arguments in [
+            ["--output", "maze.txt"],
+            ["--format", "png"],
+            ["--algorithm", "ellers"],
+            ["--style", "heavy"],
+            ["--braid", "0.5"],
+            ["--rows", "20"],
+        ] {
+            assert!(
+                Cli::try_parse_from(["mazes", "--stream", "2"].into_iter().chain(arguments))
+                    .is_err()
+            );
+        }
+
+        assert!(Cli::try_parse_from(["mazes", "--stream", "--distances"]).is_err());
+    }
+
+    #[test]
+    fn test_stats_argument() {
+        let options = Cli::try_parse_from(["mazes", "--stats", "--room-size", "3"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.stats, Some(100));
+        assert_eq!(options.compared.len(), Algorithm::value_variants().len());
+        assert_eq!(options.compared[0].to_string(), "Binary Tree");
+
+        let options = Cli::try_parse_from(["mazes", "--stats", "20"])
+            .unwrap()
+            .into_options();
+
+        assert_eq!(options.stats, Some(20));
+        assert!(Cli::try_parse_from(["mazes", "--stats", "0"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--stats", "--animate"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert!(Cli::try_parse_from(["mazes", "--algorithm", "unknown"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--rows", "ten"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--rows", "1000"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--columns", "1"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--colors"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--format", "jpeg"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--selection", "latest"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--cell-size", "0"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--cell-size", "100000"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--wall-thickness", "21"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--margin", "101"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--stream", "0"]).is_err());
+        assert!(Cli::try_parse_from(["mazes", "--switch-at", "1.5"]).is_err());
+    }
+}
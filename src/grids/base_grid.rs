@@ -24,7 +24,7 @@ pub trait WithRowsAndColumns {
     fn cell(&self, row: i32, column: i32) -> Option<&GridCell>;
 
     /// Returns an iterator over the cells of the grid.
-    fn iter(&self) -> GridIterator;
+    fn iter(&self) -> GridIterator<'_>;
 
     /// Returns an iterator over each row of the grid.
     fn each_row(&self) -> Iter<'_, Vec<GridCell>>;
@@ -37,9 +37,9 @@ pub trait WithDisplay {
     /// # Returns
     ///
     /// A `GridDisplay` instance that can be used to display the grid.
-    fn display(&mut self) -> GridDisplay;
+    fn display(&mut self) -> GridDisplay<'_>;
 
-    fn display_with_color(&mut self) -> GridDisplay;
+    fn display_with_color(&mut self) -> GridDisplay<'_>;
 }
 
 /// The `BaseGrid` trait represents a grid structure with rows, columns, and display capabilities.
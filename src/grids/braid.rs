@@ -0,0 +1,189 @@
+use super::base_grid::{GridCell, WithRowsAndColumns};
+use rand::{seq::SliceRandom, Rng, RngCore};
+
+/// The `Braid` trait removes the dead ends of a maze, turning a perfect maze into a maze with
+/// loops, where there is more than one path between two cells.
+///
+/// It is implemented for every grid.
+pub trait Braid: WithRowsAndColumns {
+    /// Returns the dead ends of the maze: the cells with a single passage.
+    fn dead_ends(&self) -> Vec<GridCell> {
+        self.iter()
+            .filter(|cell| cell.borrow().links().len() == 1)
+            .cloned()
+            .collect()
+    }
+
+    /// Links the dead ends of the maze to one of their neighbors, preferring the neighbors
+    /// that are dead ends too, so that a single passage removes two dead ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `probability` - The probability, between `0` and `1`, that a dead end is removed.
+    /// * `rng` - The random number generator used to pick the dead ends and their neighbors.
+    ///
+    /// # Returns
+    ///
+    /// The passages carved between the dead ends and their neighbors, in order.
+    fn braid(&self, probability: f64, rng: &mut dyn RngCore) -> Vec<((i32, i32), (i32, i32))> {
+        let mut dead_ends = self.dead_ends();
+        dead_ends.shuffle(rng);
+
+        let mut links = vec![];
+
+        for cell in dead_ends {
+            // A previous passage may have already removed this dead end.
+            if cell.borrow().links().len() != 1 || !rng.gen_bool(probability.clamp(0.0, 1.0)) {
+                continue;
+            }
+
+            let neighbors = cell
+                .borrow()
+                .neighbors()
+                .into_iter()
+                .filter(|neighbor| !cell.borrow().links().contains_key(neighbor))
+                .filter_map(|(row, column)| self.cell(row, column))
+                .collect::<Vec<&GridCell>>();
+
+            let preferred = neighbors
+                .iter()
+                .copied()
+                .filter(|neighbor| neighbor.borrow().links().len() == 1)
+                .collect::<Vec<&GridCell>>();
+
+            let candidates = if preferred.is_empty() {
+                neighbors
+            } else {
+                preferred
+            };
+
+            let Some(neighbor) = candidates.choose(rng) else {
+                continue;
+            };
+
+            cell.borrow_mut().link((*neighbor).clone());
+            links.push((
+                cell.borrow().to_row_and_column(),
+                neighbor.borrow().to_row_and_column(),
+            ));
+        }
+
+        links
+    }
+}
+
+/// Implement the `Braid` trait for every grid.
+impl<T> Braid for T where T: WithRowsAndColumns + ?Sized {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{passages, recursive_backtracker::RecursiveBacktracker, On},
+        grids::{grid::Grid, test_support::link},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn maze(seed: u64) -> Grid {
+        let mut grid = Grid::new(10, 10);
+        RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(seed));
+
+        grid
+    }
+
+    #[test]
+    fn test_dead_ends() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+
+        let dead_ends = grid
+            .dead_ends()
+            .iter()
+            .map(|cell| cell.borrow().to_row_and_column())
+            .collect::<Vec<(i32, i32)>>();
+
+        assert_eq!(dead_ends, vec![(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn test_braid_removes_every_dead_end() {
+        let grid = maze(1);
+        let (before, _) = passages(&grid);
+
+        assert!(!grid.dead_ends().is_empty());
+
+        let links = grid.braid(1.0, &mut StdRng::seed_from_u64(2));
+
+        assert!(grid.dead_ends().is_empty());
+        assert_eq!(passages(&grid), (before + links.len(), true));
+    }
+
+    #[test]
+    fn test_braid_prefers_dead_ends() {
+        for seed in 0..10 {
+            let grid = Grid::new(3, 3);
+
+            for (from, to) in [
+                ((0, 0), (0, 1)),
+                ((0, 1), (0, 2)),
+                ((0, 1), (1, 1)),
+                ((0, 0), (1, 0)),
+                ((1, 0), (2, 0)),
+                ((2, 0), (2, 1)),
+                ((0, 2), (1, 2)),
+                ((1, 2), (2, 2)),
+            ] {
+                link(&grid, from, to);
+            }
+
+            let dead_ends = [(1, 1), (2, 1), (2, 2)];
+            let links = grid.braid(1.0, &mut StdRng::seed_from_u64(seed));
+
+            // Whichever dead end comes first, it is linked to another dead end.
+            assert_eq!(links.len(), 2);
+            assert!(dead_ends.contains(&links[0].0) && dead_ends.contains(&links[0].1));
+            assert!(grid.dead_ends().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_braid_links_pairs_of_dead_ends() {
+        let grid = maze(8);
+        let dead_ends = grid.dead_ends().len();
+
+        let links = grid.braid(1.0, &mut StdRng::seed_from_u64(9));
+
+        assert!(links.len() < dead_ends);
+    }
+
+    #[test]
+    fn test_braid_probability() {
+        let untouched = maze(4);
+        let dead_ends = untouched.dead_ends().len();
+
+        assert!(untouched
+            .braid(0.0, &mut StdRng::seed_from_u64(5))
+            .is_empty());
+        assert_eq!(untouched.dead_ends().len(), dead_ends);
+
+        let half = maze(4);
+        let links = half.braid(0.5, &mut StdRng::seed_from_u64(5));
+
+        assert!(!links.is_empty());
+        assert!(!half.dead_ends().is_empty());
+        assert!(half.dead_ends().len() < dead_ends);
+    }
+
+    #[test]
+    fn test_same_seed_braids_the_same_maze() {
+        let first = maze(6);
+        let second = maze(6);
+
+        first.braid(0.5, &mut StdRng::seed_from_u64(7));
+        second.braid(0.5, &mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first, second);
+    }
+}
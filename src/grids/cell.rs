@@ -74,7 +74,7 @@ impl Cell {
             .insert((self.row, self.column), true);
     }
 
-    /// Unlinks the cell from another cell.
+    /// Unlinks the cell from another cell, on both sides.
     ///
     /// # Arguments
     ///
@@ -83,6 +83,8 @@ impl Cell {
         let (row, column) = (other.borrow().row, other.borrow().column);
 
         self.links.remove(&(row, column));
+
+        other.borrow_mut().links.remove(&(self.row, self.column));
     }
 
     /// Returns the neighboring cells of the cell.
@@ -216,9 +218,10 @@ mod tests {
         let cell2 = Grid::new_grid_cell(1, 1);
 
         cell1.link(cell2.clone());
-        cell1.unlink(cell2);
+        cell1.unlink(cell2.clone());
 
         assert_eq!(cell1.links.get(&(1, 1)), None);
+        assert_eq!(cell2.borrow().links.get(&(0, 0)), None);
     }
 
     #[test]
@@ -3,7 +3,10 @@ use super::{
     distances::Distances,
     grid::Grid,
     grid_display::GridDisplay,
+    path::Path,
+    root::Root,
 };
+use crate::utils::color::distance_color;
 use colored::Colorize;
 use radix_fmt::radix_36;
 
@@ -11,6 +14,11 @@ use radix_fmt::radix_36;
 pub struct DistanceGrid {
     grid: Grid,
     distances: Distances,
+    path: Option<Path>,
+    root: Root,
+    goal: Option<Root>,
+    longest_path: bool,
+    markers: bool,
 }
 
 impl DistanceGrid {
@@ -27,42 +35,158 @@ impl DistanceGrid {
     pub fn new(rows: i32, columns: i32) -> Self {
         Self {
             distances: Distances::new((0, 0)),
+            path: None,
             grid: Grid::new(rows, columns),
+            root: Root::default(),
+            goal: None,
+            longest_path: false,
+            markers: false,
         }
     }
 
-    /// Displays the grid with the path to the specified goal cell.
+    /// Sets the cell the distances are measured from; the north west cell by default, or when
+    /// the cell is outside the grid.
+    pub fn with_root(mut self, root: Root) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Sets the goal of the path displayed from the root instead of the distances of every
+    /// cell; with `None`, or when the goal cannot be reached, the distances of every cell are
+    /// displayed.
+    pub fn with_goal(mut self, goal: Option<Root>) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    /// Sets whether the grid displays the longest path of the maze instead of the distances
+    /// from the root.
+    pub fn with_longest_path(mut self, longest_path: bool) -> Self {
+        self.longest_path = longest_path;
+        self
+    }
+
+    /// Sets whether the ends of the displayed path are marked with `s`, the start, and `g`, the
+    /// goal, instead of their distances; the markers are lowercase so that they are not mistaken
+    /// for distances in base 36.
+    pub fn with_markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Returns the label displayed for a distance, in base 36.
     ///
     /// # Arguments
     ///
-    /// * `goal` - The goal cell to find the path to.
+    /// * `distance` - The distance to display.
+    ///
+    /// # Returns
+    ///
+    /// The distance formatted in base 36.
+    fn label(distance: i32) -> String {
+        format!("{:#}", radix_36(distance))
+    }
+
+    /// Returns the label displayed for a cell of a path: its distance from the start of the
+    /// path, or its marker if it is an end of the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path, if any.
+    /// * `cell` - The row and the column of the cell.
+    /// * `markers` - Whether the ends of the path are marked.
+    ///
+    /// # Returns
+    ///
+    /// The label of the cell, empty if the cell is not on the path.
+    fn path_label(path: Option<&Path>, cell: (i32, i32), markers: bool) -> String {
+        let Some(path) = path else {
+            return String::new();
+        };
+
+        match path.distance(cell) {
+            Some(_) if markers && cell == path.start() => String::from("s"),
+            Some(_) if markers && cell == path.goal() => String::from("g"),
+            Some(distance) => Self::label(distance),
+            None => String::new(),
+        }
+    }
+
+    /// Displays the grid with the longest path of the maze, the path between the two cells
+    /// farthest apart, which are the most challenging entrances of the maze.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    pub fn display_longest_path(&mut self) -> GridDisplay<'_> {
+        self.path = Some(Distances::longest_path(&self.grid));
+
+        self.display_path()
+    }
+
+    /// Measures the distances displayed by the grid: the longest path of the maze, the path
+    /// from the root to the goal, or the distances of every cell from the root.
+    fn measure(&mut self) {
+        if self.longest_path {
+            self.path = Some(Distances::longest_path(&self.grid));
+            return;
+        }
+
+        let root = self.root.resolve(&self.grid).unwrap_or((0, 0));
+        let cell = self.cell(root.0, root.1).unwrap().to_owned();
+
+        self.distances = Distances::new(root);
+        self.distances.calculate(cell, &self.grid);
+
+        self.path = self
+            .goal
+            .as_ref()
+            .and_then(|goal| goal.resolve(&self.grid))
+            .and_then(|goal| {
+                let goal = self.cell(goal.0, goal.1).unwrap().to_owned();
+                self.distances.path_to(goal, &self.grid).ok()
+            });
+    }
+
+    /// Displays the grid with the measured path, leaving the other cells empty.
     ///
     /// # Returns
     ///
     /// A `GridDisplay` instance that can be used to display the grid.
-    pub fn display_path_to(&mut self, goal: GridCell) -> GridDisplay {
-        let root = self.cell(0, 0).unwrap().to_owned();
-        self.distances
-            .calculate(root, &self.grid)
-            .path_to(goal, &self.grid);
+    fn display_path(&self) -> GridDisplay<'_> {
+        let path = self.path.as_ref();
+        let label_width = Self::label(path.map_or(0, Path::length)).len();
+        let markers = self.markers;
 
         GridDisplay::new(
             &self.grid,
-            Box::new(|cell: GridCell| {
-                let row = cell.borrow_mut().row();
-                let column = cell.borrow_mut().column();
-                let distance = self.distances.get((row, column)).unwrap_or(&0);
-
-                format!(
-                    " {} ",
-                    if *distance > 0 {
-                        format!("{distance:X}")
-                    } else {
-                        " ".to_string()
-                    }
-                )
+            Box::new(move |cell: GridCell| {
+                let cell = cell.borrow().to_row_and_column();
+
+                format!(" {:>label_width$} ", Self::path_label(path, cell, markers))
             }),
         )
+        .with_cell_width(label_width + 2)
+    }
+
+    /// Displays the grid with the path to the specified goal cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `goal` - The goal cell to find the path to.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    pub fn display_path_to(&mut self, goal: GridCell) -> GridDisplay<'_> {
+        let root = self.root.resolve(&self.grid).unwrap_or((0, 0));
+        let cell = self.cell(root.0, root.1).unwrap().to_owned();
+
+        self.distances = Distances::new(root);
+        self.distances.calculate(cell, &self.grid);
+        self.path = self.distances.path_to(goal, &self.grid).ok();
+
+        self.display_path()
     }
 }
 
@@ -79,7 +203,7 @@ impl WithRowsAndColumns for DistanceGrid {
         self.grid.cell(row, column)
     }
 
-    fn iter(&self) -> GridIterator {
+    fn iter(&self) -> GridIterator<'_> {
         self.grid.iter()
     }
 
@@ -94,20 +218,28 @@ impl WithDisplay for DistanceGrid {
     /// # Returns
     ///
     /// A `GridDisplay` instance that can be used to display the grid.
-    fn display(&mut self) -> GridDisplay {
-        let root = self.cell(0, 0).unwrap().to_owned();
-        self.distances.calculate(root, &self.grid);
+    fn display(&mut self) -> GridDisplay<'_> {
+        self.measure();
+
+        if self.path.is_some() {
+            return self.display_path();
+        }
+
+        let label_width = Self::label(self.distances.max_distance().value()).len();
+
+        let distances = &self.distances;
 
         GridDisplay::new(
             &self.grid,
-            Box::new(|cell: GridCell| {
+            Box::new(move |cell: GridCell| {
                 let row = cell.borrow_mut().row();
                 let column = cell.borrow_mut().column();
-                let distance = self.distances.get((row, column)).unwrap_or(&0);
+                let distance = distances.get((row, column)).unwrap_or(&0);
 
-                format!(" {:#} ", radix_36(*distance))
+                format!(" {:>label_width$} ", Self::label(*distance))
             }),
         )
+        .with_cell_width(label_width + 2)
     }
 
     /// Displays the grid with colors based on the distances between cells.
@@ -115,34 +247,53 @@ impl WithDisplay for DistanceGrid {
     /// # Returns
     ///
     /// A `GridDisplay` instance that can be used to display the grid.
-    fn display_with_color(&mut self) -> GridDisplay {
-        let root = self.cell(0, 0).unwrap().to_owned();
-        self.distances.calculate(root, &self.grid);
+    fn display_with_color(&mut self) -> GridDisplay<'_> {
+        self.measure();
+
+        let path = self.path.as_ref();
+        let max_distance = path.map_or(self.distances.max_distance().value(), Path::length);
+        let label_width = Self::label(max_distance).len();
+
+        let distances = &self.distances;
+        let markers = self.markers;
 
         GridDisplay::new(
             &self.grid,
-            Box::new(|cell: GridCell| {
+            Box::new(move |cell: GridCell| {
                 let row = cell.borrow_mut().row();
                 let column = cell.borrow_mut().column();
-                let distance = self.distances.get((row, column)).unwrap_or(&0);
-                let max_distance = self.distances.max_distance().value();
 
-                let intensity = f64::from(max_distance - distance) / f64::from(max_distance);
-                let dark = (255.0 * intensity).floor();
-                let bright = 128.0 + (127.0 * intensity).floor();
+                // Only the cells of the path are colored.
+                let (label, distance) = match path {
+                    Some(path) => match path.distance((row, column)) {
+                        Some(distance) => (
+                            Self::path_label(Some(path), (row, column), markers),
+                            distance,
+                        ),
+                        None => return " ".repeat(label_width + 2),
+                    },
+                    None => {
+                        let distance = *distances.get((row, column)).unwrap_or(&0);
+                        (Self::label(distance), distance)
+                    }
+                };
+
+                let [red, green, blue] = distance_color(distance, max_distance);
 
-                format!(" {:#} ", radix_36(*distance))
+                format!(" {label:>label_width$} ")
                     .as_str()
-                    .on_truecolor(dark as u8, dark as u8, bright as u8)
+                    .on_truecolor(red, green, blue)
                     .to_string()
             }),
         )
+        .with_cell_width(label_width + 2)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grids::test_support::link;
 
     #[test]
     fn test_new_distance_grid() {
@@ -174,4 +325,121 @@ mod tests {
                 .trim(),
         );
     }
+
+    #[test]
+    fn test_display_adapts_cell_width_to_widest_distance() {
+        let mut distance_grid = DistanceGrid::new(1, 40);
+
+        for column in 0..39 {
+            let cell = distance_grid.cell(0, column).unwrap().clone();
+            let east = distance_grid.cell(0, column + 1).unwrap().clone();
+            cell.borrow_mut().link(east);
+        }
+
+        let display = distance_grid.display().to_string();
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert_eq!(lines[0], format!("+{}", "----+".repeat(40)));
+        assert!(lines[1].starts_with("|  0    1    2 "));
+        assert!(lines[1].ends_with(" 12   13 |"));
+    }
+
+    fn longest_corridor() -> DistanceGrid {
+        // A corridor from the south east corner, the farthest from the north west corner,
+        // through the north row, to the south west corner: the north west corner is not an end
+        // of the longest path.
+        let distance_grid = DistanceGrid::new(2, 3);
+        link(&distance_grid, (1, 0), (0, 0));
+        link(&distance_grid, (0, 0), (0, 1));
+        link(&distance_grid, (0, 1), (0, 2));
+        link(&distance_grid, (0, 2), (1, 2));
+        link(&distance_grid, (0, 1), (1, 1));
+
+        distance_grid
+    }
+
+    #[test]
+    fn test_display_longest_path() {
+        let mut distance_grid = longest_corridor();
+
+        assert_eq!(
+            distance_grid.display_longest_path().to_string(),
+            "+---+---+---+\n\
+             | 3   2   1 |\n\
+             +   +   +   +\n\
+             | 4 |   | 0 |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_longest_path_with_markers() {
+        let mut distance_grid = longest_corridor()
+            .with_longest_path(true)
+            .with_markers(true);
+
+        assert_eq!(
+            distance_grid.display().to_string(),
+            "+---+---+---+\n\
+             | 3   2   1 |\n\
+             +   +   +   +\n\
+             | g |   | s |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_from_root() {
+        let mut distance_grid = longest_corridor().with_root(Root::Cell((0, 1)));
+
+        assert_eq!(
+            distance_grid.display().to_string(),
+            "+---+---+---+\n\
+             | 1   0   1 |\n\
+             +   +   +   +\n\
+             | 2 | 1 | 2 |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_path_to_goal() {
+        let mut distance_grid = longest_corridor()
+            .with_root(Root::Center)
+            .with_goal(Some(Root::FarthestFrom(Box::new(Root::Center))))
+            .with_markers(true);
+
+        assert_eq!(
+            distance_grid.display().to_string(),
+            "+---+---+---+\n\
+             | 2   1     |\n\
+             +   +   +   +\n\
+             | g | s |   |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_path_to() {
+        let mut distance_grid = DistanceGrid::new(2, 9);
+
+        for column in 0..8 {
+            link(&distance_grid, (0, column), (0, column + 1));
+            link(&distance_grid, (1, column), (1, column + 1));
+        }
+
+        link(&distance_grid, (0, 8), (1, 8));
+
+        let goal = distance_grid.cell(1, 0).unwrap().clone();
+
+        // The distances are in base 36, like the other displays, and the root shows its 0.
+        assert_eq!(
+            distance_grid.display_path_to(goal).to_string(),
+            "+---+---+---+---+---+---+---+---+---+\n\
+             | 0   1   2   3   4   5   6   7   8 |\n\
+             +---+---+---+---+---+---+---+---+   +\n\
+             | H   G   F   E   D   C   B   A   9 |\n\
+             +---+---+---+---+---+---+---+---+---+\n"
+        );
+    }
 }
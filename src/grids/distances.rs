@@ -1,8 +1,18 @@
-use super::base_grid::{GridCell, WithRowsAndColumns};
-use std::collections::HashMap;
+use super::{
+    base_grid::{GridCell, WithRowsAndColumns},
+    path::{Path, PathError},
+};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+/// The cells at the same distance from the root, reached by a step of the breadth-first search.
+pub type Frontier = [(i32, i32)];
 
 /// Represents the maximum distance in a Grid from a root cell.
 pub struct MaxDistance {
+    cell: (i32, i32),
     value: i32,
 }
 
@@ -11,13 +21,23 @@ impl MaxDistance {
     ///
     /// # Arguments
     ///
+    /// * `cell` - The cell farthest from the root cell.
     /// * `value` - The maximum distance from the root cell to the maximum cell.
     ///
     /// # Returns
     ///
     /// A new instance of `MaxDistance`.
-    pub fn new(value: i32) -> Self {
-        Self { value }
+    pub fn new(cell: (i32, i32), value: i32) -> Self {
+        Self { cell, value }
+    }
+
+    /// Returns the cell farthest from the root cell.
+    ///
+    /// # Returns
+    ///
+    /// The row and the column of the maximum cell.
+    pub fn cell(&self) -> (i32, i32) {
+        self.cell
     }
 
     /// Returns the maximum distance from the root cell to the maximum cell.
@@ -54,6 +74,44 @@ impl Distances {
         Self { root, cells }
     }
 
+    /// Finds the longest path of the maze: the path between the two cells farthest apart.
+    ///
+    /// The cell farthest from any cell is an end of the longest path of a perfect maze, so two
+    /// breadth-first searches find it: the first from the north west cell, the second from the
+    /// farthest cell of the first one, which reaches the other end of the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid on which the longest path is found.
+    ///
+    /// # Returns
+    ///
+    /// The longest `Path`, from its start to the farthest cell, its goal.
+    pub fn longest_path(grid: &(impl WithRowsAndColumns + ?Sized)) -> Path {
+        let Some(north_west) = grid.cell(0, 0) else {
+            return Path::new(vec![(0, 0)], vec![0]);
+        };
+
+        let mut distances = Self::new((0, 0));
+        distances.calculate(north_west.clone(), grid);
+
+        let start = distances.max_distance().cell();
+        let mut distances = Self::new(start);
+        distances.calculate(grid.cell(start.0, start.1).unwrap().clone(), grid);
+
+        let goal = distances.max_distance().cell();
+
+        // The farthest cell was reached by the search, so there is a path to it.
+        distances
+            .path_to(grid.cell(goal.0, goal.1).unwrap().clone(), grid)
+            .unwrap()
+    }
+
+    /// Returns the root cell from which the distances are measured.
+    pub fn root(&self) -> (i32, i32) {
+        self.root
+    }
+
     /// Returns the distance from the root cell to the specified cell, if it exists.
     ///
     /// # Arguments
@@ -87,10 +145,42 @@ impl Distances {
     /// # Returns
     ///
     /// A mutable reference to `Self` (the `Distances` instance) after the calculation is complete.
-    pub fn calculate(&mut self, root: GridCell, grid: &impl WithRowsAndColumns) -> &mut Self {
+    pub fn calculate(
+        &mut self,
+        root: GridCell,
+        grid: &(impl WithRowsAndColumns + ?Sized),
+    ) -> &mut Self {
+        self.calculate_observed(root, grid, &mut |_| {})
+    }
+
+    /// Calculates the distances from the root cell to all other cells in the grid, reporting
+    /// every frontier of the breadth-first search to the observer.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root cell from which to calculate the distances.
+    /// * `grid` - The grid on which the distances are calculated.
+    /// * `observer` - The observer notified of every frontier: the cells at the same distance
+    ///   from the root, starting from the root itself.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to `Self` (the `Distances` instance) after the calculation is complete.
+    pub fn calculate_observed(
+        &mut self,
+        root: GridCell,
+        grid: &(impl WithRowsAndColumns + ?Sized),
+        observer: &mut dyn FnMut(&Frontier),
+    ) -> &mut Self {
         let mut frontier = vec![root];
 
         while !frontier.is_empty() {
+            let positions = frontier
+                .iter()
+                .map(|cell| cell.borrow().to_row_and_column())
+                .collect::<Vec<(i32, i32)>>();
+            observer(&positions);
+
             let mut new_frontier = vec![];
 
             for cell in frontier {
@@ -118,86 +208,141 @@ impl Distances {
         self
     }
 
-    /// Calculates the shortest path from the root cell to the specified goal cell.
+    /// Calculates the distances from the root cell to all other cells in the grid, when
+    /// entering a cell costs its weight instead of a single step.
+    ///
+    /// The cells are reached in order of distance, with Dijkstra's algorithm: a priority queue
+    /// always hands out the closest cell whose distance is not final yet.
     ///
     /// # Arguments
     ///
-    /// * `goal` - The goal cell to which the shortest path is calculated.
-    /// * `grid` - The grid on which the shortest path is calculated.
+    /// * `root` - The root cell from which to calculate the distances.
+    /// * `grid` - The grid on which the distances are calculated.
+    /// * `weight` - Returns the cost of entering a cell, at least `1`; the distances stop growing
+    ///   at `i32::MAX` instead of overflowing.
     ///
     /// # Returns
     ///
-    /// A mutable reference to `Self` (the `Distances` instance) after the shortest path calculation is complete.
-    pub fn path_to(&mut self, goal: GridCell, grid: &impl WithRowsAndColumns) -> &mut Self {
-        let mut current: std::rc::Rc<std::cell::RefCell<super::cell::Cell>> = goal;
-        let mut breadcrumbs = Distances::new(self.root);
+    /// A mutable reference to `Self` (the `Distances` instance) after the calculation is complete.
+    pub fn calculate_weighted(
+        &mut self,
+        root: GridCell,
+        grid: &(impl WithRowsAndColumns + ?Sized),
+        weight: &dyn Fn((i32, i32)) -> i32,
+    ) -> &mut Self {
+        let mut queue = BinaryHeap::new();
+        queue.push(Reverse((0, root.borrow().to_row_and_column())));
+
+        while let Some(Reverse((distance, position))) = queue.pop() {
+            // The cell was queued again, closer, and has already been handled.
+            if self.get(position).is_some_and(|known| *known < distance) {
+                continue;
+            }
 
-        let mut current_cell = current.borrow().to_row_and_column();
+            let Some(cell) = grid.cell(position.0, position.1) else {
+                continue;
+            };
 
-        if let Some(distance) = self.get(current_cell) {
-            breadcrumbs.set(current_cell, *distance);
-        } else {
-            return self;
-        }
+            for linked in cell.borrow().links().keys() {
+                let candidate = distance.saturating_add(weight(*linked).max(1));
 
-        while current_cell != self.root {
-            if let Some(distance) = self.get(current_cell) {
-                let mut next_current = None;
-
-                for neighbour in current.borrow().links().keys() {
-                    if let Some(neighbour_distance) = self.get(*neighbour) {
-                        if *neighbour_distance < *distance {
-                            breadcrumbs.set(*neighbour, *neighbour_distance);
-                            next_current =
-                                Some(grid.cell(neighbour.0, neighbour.1).unwrap().clone());
-                            break;
-                        }
-                    } else {
-                        return self;
-                    }
+                if self.get(*linked).is_none_or(|known| candidate < *known) {
+                    self.set(*linked, candidate);
+                    queue.push(Reverse((candidate, *linked)));
                 }
-
-                if let Some(new_current) = next_current {
-                    current = new_current;
-                    current_cell = current.borrow().to_row_and_column();
-                } else {
-                    break;
-                }
-            } else {
-                return self;
             }
         }
 
-        self.cells = breadcrumbs.cells;
-
         self
     }
 
+    /// Finds the shortest path from the root cell to the specified goal cell, walking back from
+    /// the goal through the closest linked neighbors.
+    ///
+    /// The distances are left untouched, so that a single calculation serves as many paths as
+    /// needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `goal` - The goal cell to which the shortest path is found.
+    /// * `grid` - The grid on which the distances were calculated.
+    ///
+    /// # Returns
+    ///
+    /// The `Path` from the root to the goal, or a `PathError` if the goal has no distance or the
+    /// distances do not match the grid.
+    pub fn path_to(
+        &self,
+        goal: GridCell,
+        grid: &(impl WithRowsAndColumns + ?Sized),
+    ) -> Result<Path, PathError> {
+        let goal = goal.borrow().to_row_and_column();
+        let distance = *self.get(goal).ok_or(PathError::Unreachable(goal))?;
+
+        let mut cells = vec![goal];
+        let mut distances = vec![distance];
+        let (mut current, mut current_distance) = (goal, distance);
+
+        while current != self.root {
+            let cell = grid
+                .cell(current.0, current.1)
+                .ok_or(PathError::Broken(current))?;
+
+            // The closest neighbour comes before the current cell on a shortest path, even
+            // when the cells are weighted: entering a cell costs the same from any neighbour.
+            let (neighbour_distance, neighbour) = cell
+                .borrow()
+                .links()
+                .keys()
+                .filter_map(|neighbour| {
+                    self.get(*neighbour)
+                        .map(|neighbour_distance| (*neighbour_distance, *neighbour))
+                })
+                .filter(|(neighbour_distance, _)| *neighbour_distance < current_distance)
+                .min()
+                .ok_or(PathError::Broken(current))?;
+
+            cells.push(neighbour);
+            distances.push(neighbour_distance);
+            (current, current_distance) = (neighbour, neighbour_distance);
+        }
+
+        cells.reverse();
+        distances.reverse();
+
+        Ok(Path::new(cells, distances))
+    }
+
     /// Calculates the maximum distance in the grid from the root cell to any other cell.
     ///
     /// # Returns
     ///
     /// A `MaxDistance` struct containing the maximum cell and distance.
     pub fn max_distance(&self) -> MaxDistance {
+        let mut max_cell = self.root;
         let mut max_distance = 0;
 
-        for (_, distance) in self.cells.clone() {
-            if distance > max_distance {
-                max_distance = distance;
+        for (cell, distance) in self.cells.iter() {
+            // Among the farthest cells, the first in row-major order wins, whatever the order
+            // of the map.
+            if *distance > max_distance || *distance == max_distance && *cell < max_cell {
+                max_cell = *cell;
+                max_distance = *distance;
             }
         }
 
-        MaxDistance::new(max_distance)
+        MaxDistance::new(max_cell, max_distance)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::grid::Grid;
-    use super::Distances;
+    use super::{Distances, PathError};
+    use crate::algorithms::binary_tree::BinaryTree;
     use crate::algorithms::On;
     use crate::grids::base_grid::WithRowsAndColumns;
-    use crate::BinaryTree;
+    use crate::grids::test_support::link;
     use std::collections::HashMap;
 
     #[test]
@@ -223,7 +368,7 @@ mod tests {
     fn test_calculate() {
         let mut grid = Grid::new(3, 3);
         let binary_tree = BinaryTree::new();
-        binary_tree.on(&mut grid);
+        binary_tree.on(&mut grid, &mut rand::thread_rng());
 
         let root = grid.cell(0, 0).unwrap();
         let mut distances = Distances::new((0, 0));
@@ -238,19 +383,80 @@ mod tests {
         assert_eq!(distances.cells.keys().len(), 9);
     }
 
+    #[test]
+    fn test_calculate_observed() {
+        let mut grid = Grid::new(4, 4);
+        BinaryTree::new().on(&mut grid, &mut rand::thread_rng());
+
+        let root = grid.cell(0, 0).unwrap().clone();
+        let mut distances = Distances::new((0, 0));
+        let mut frontiers = vec![];
+        distances.calculate_observed(root, &grid, &mut |frontier| {
+            frontiers.push(frontier.to_vec());
+        });
+
+        assert_eq!(frontiers[0], vec![(0, 0)]);
+        assert_eq!(frontiers.iter().map(Vec::len).sum::<usize>(), 16);
+
+        for (distance, frontier) in frontiers.iter().enumerate() {
+            for cell in frontier {
+                assert_eq!(distances.get(*cell), Some(&(distance as i32)));
+            }
+        }
+    }
+
     #[test]
     fn test_path_to() {
         let mut grid = Grid::new(3, 3);
         let binary_tree = BinaryTree::new();
-        binary_tree.on(&mut grid);
+        binary_tree.on(&mut grid, &mut rand::thread_rng());
 
         let root = grid.cell(0, 0).unwrap().clone();
         let goal = grid.cell(2, 2).unwrap().clone();
 
         let mut distances = Distances::new(root.borrow().to_row_and_column());
-        distances.calculate(root, &grid).path_to(goal, &grid);
+        distances.calculate(root, &grid);
+
+        let path = distances.path_to(goal.clone(), &grid).unwrap();
+
+        assert_eq!(path.start(), (0, 0));
+        assert_eq!(path.goal(), (2, 2));
+        assert_eq!(path.length(), *distances.get((2, 2)).unwrap());
+        assert_eq!(path.cells().len() as i32, path.length() + 1);
+
+        // Every step of the path goes through a passage, one cell closer to the goal.
+        for (index, step) in path.cells().windows(2).enumerate() {
+            let cell = grid.cell(step[0].0, step[0].1).unwrap().borrow();
 
-        assert_eq!(distances.get((0, 0)), Some(&0));
+            assert!(cell.links().contains_key(&step[1]));
+            assert_eq!(path.distance(step[1]), Some(index as i32 + 1));
+        }
+
+        // The distances are left untouched, for another path.
+        assert_eq!(distances.cells.len(), 9);
+        assert_eq!(distances.path_to(goal, &grid).unwrap(), path);
+    }
+
+    #[test]
+    fn test_path_to_errors() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(grid.cell(0, 0).unwrap().clone(), &grid);
+
+        assert_eq!(
+            distances.path_to(grid.cell(1, 1).unwrap().clone(), &grid),
+            Err(PathError::Unreachable((1, 1)))
+        );
+
+        // A distance that no passage leads to.
+        distances.set((1, 1), 2);
+
+        assert_eq!(
+            distances.path_to(grid.cell(1, 1).unwrap().clone(), &grid),
+            Err(PathError::Broken((1, 1)))
+        );
     }
 
     #[test]
@@ -264,5 +470,98 @@ mod tests {
         let max_distance = distances.max_distance();
 
         assert_eq!(max_distance.value, 3);
+        assert_eq!(max_distance.cell(), (3, 0));
+    }
+
+    #[test]
+    fn test_calculate_weighted() {
+        // A loop around the grid, with a costly cell on the short way round.
+        //
+        // +---+---+---+
+        // |           |
+        // +   +---+   +
+        // |           |
+        // +---+---+---+
+        let grid = Grid::new(2, 3);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (0, 2));
+        link(&grid, (0, 2), (1, 2));
+        link(&grid, (1, 2), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+        link(&grid, (1, 0), (0, 0));
+
+        let lava = |cell: (i32, i32)| if cell == (1, 0) { 50 } else { 1 };
+        let root = grid.cell(0, 0).unwrap().clone();
+        let mut distances = Distances::new((0, 0));
+        distances.calculate_weighted(root, &grid, &lava);
+
+        assert_eq!(distances.get((0, 2)), Some(&2));
+        // The long way round avoids the lava.
+        assert_eq!(distances.get((1, 1)), Some(&4));
+        // Entering the lava costs the same from either side.
+        assert_eq!(distances.get((1, 0)), Some(&50));
+
+        let goal = grid.cell(1, 1).unwrap().clone();
+        let path = distances.path_to(goal, &grid).unwrap();
+
+        assert_eq!(path.cells(), [(0, 0), (0, 1), (0, 2), (1, 2), (1, 1)]);
+        assert_eq!(path.length(), 4);
+    }
+
+    #[test]
+    fn test_calculate_weighted_with_huge_weights() {
+        let grid = Grid::new(1, 3);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (0, 2));
+
+        let root = grid.cell(0, 0).unwrap().clone();
+        let mut distances = Distances::new((0, 0));
+        distances.calculate_weighted(root, &grid, &|_| i32::MAX - 1);
+
+        assert_eq!(distances.get((0, 1)), Some(&(i32::MAX - 1)));
+        assert_eq!(distances.get((0, 2)), Some(&i32::MAX));
+    }
+
+    #[test]
+    fn test_calculate_weighted_with_unit_weights() {
+        let mut grid = Grid::new(6, 6);
+        BinaryTree::new().on(&mut grid, &mut rand::thread_rng());
+
+        let root = grid.cell(0, 0).unwrap().clone();
+        let mut steps = Distances::new((0, 0));
+        let mut weighted = Distances::new((0, 0));
+        steps.calculate(root.clone(), &grid);
+        weighted.calculate_weighted(root, &grid, &|_| 1);
+
+        assert_eq!(steps.cells, weighted.cells);
+    }
+
+    #[test]
+    fn test_longest_path() {
+        // +---+---+---+
+        // |       |   |
+        // +   +   +   +
+        // |   |       |
+        // +---+---+   +
+        // |           |
+        // +---+---+---+
+        let grid = Grid::new(3, 3);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 0), (1, 0));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 2));
+        link(&grid, (1, 2), (0, 2));
+        link(&grid, (1, 2), (2, 2));
+        link(&grid, (2, 2), (2, 1));
+        link(&grid, (2, 1), (2, 0));
+
+        let path = Distances::longest_path(&grid);
+
+        // From the south west corner to the west cell of the middle row, the long way round.
+        assert_eq!(path.start(), (2, 0));
+        assert_eq!(path.goal(), (1, 0));
+        assert_eq!(path.length(), 7);
+        assert_eq!(path.cells().len(), 8);
+        assert!(!path.contains((0, 2)));
     }
 }
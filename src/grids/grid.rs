@@ -127,7 +127,7 @@ impl WithRowsAndColumns for Grid {
     /// # Returns
     ///
     /// A `GridMutIterator` struct
-    fn iter(&self) -> GridIterator {
+    fn iter(&self) -> GridIterator<'_> {
         GridIterator::new(self.cells.iter().flatten())
     }
 
@@ -149,7 +149,7 @@ impl WithDisplay for Grid {
     /// # Returns
     ///
     /// A `GridDisplay` instance for displaying the grid.
-    fn display(&mut self) -> GridDisplay {
+    fn display(&mut self) -> GridDisplay<'_> {
         GridDisplay::new(self, Box::new(|_| String::from("   ")))
     }
 
@@ -161,7 +161,7 @@ impl WithDisplay for Grid {
     /// # Returns
     ///
     /// A `GridDisplay` instance for displaying the grid.
-    fn display_with_color(&mut self) -> GridDisplay {
+    fn display_with_color(&mut self) -> GridDisplay<'_> {
         self.display()
     }
 }
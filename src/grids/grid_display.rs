@@ -2,10 +2,77 @@ use super::{base_grid::GridCell, grid::Grid};
 use crate::grids::base_grid::WithRowsAndColumns;
 use std::fmt::Display;
 
+/// The default width, in characters, of the body of a cell.
+pub const DEFAULT_CELL_WIDTH: usize = 3;
+
+/// The characters used to draw the walls of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// The `+---+` and `|` ASCII characters.
+    #[default]
+    Ascii,
+    /// Light box-drawing characters, like `┌ ┬ ┐`.
+    Light,
+    /// Heavy box-drawing characters, like `┏ ┳ ┓`.
+    Heavy,
+    /// Light box-drawing characters with rounded corners, like `╭ ┬ ╮`.
+    Rounded,
+}
+
+impl Style {
+    /// Returns every available style.
+    pub fn all() -> Vec<Style> {
+        vec![Style::Ascii, Style::Light, Style::Heavy, Style::Rounded]
+    }
+
+    /// Returns the box-drawing characters of the style, indexed by the walls meeting at a
+    /// corner: `UP`, `DOWN`, `LEFT` and `RIGHT`, combined as a bitmask.
+    ///
+    /// # Returns
+    ///
+    /// The characters of the style, or `None` for the `Ascii` style.
+    fn glyphs(self) -> Option<[char; 16]> {
+        match self {
+            Style::Ascii => None,
+            Style::Light => Some([
+                ' ', '╵', '╷', '│', '╴', '┘', '┐', '┤', '╶', '└', '┌', '├', '─', '┴', '┬', '┼',
+            ]),
+            Style::Heavy => Some([
+                ' ', '╹', '╻', '┃', '╸', '┛', '┓', '┫', '╺', '┗', '┏', '┣', '━', '┻', '┳', '╋',
+            ]),
+            Style::Rounded => Some([
+                ' ', '╵', '╷', '│', '╴', '╯', '╮', '┤', '╶', '╰', '╭', '├', '─', '┴', '┬', '┼',
+            ]),
+        }
+    }
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Style::Ascii => write!(f, "ASCII"),
+            Style::Light => write!(f, "Light box drawing"),
+            Style::Heavy => write!(f, "Heavy box drawing"),
+            Style::Rounded => write!(f, "Rounded box drawing"),
+        }
+    }
+}
+
+/// A wall going up from a corner.
+const UP: usize = 1;
+/// A wall going down from a corner.
+const DOWN: usize = 2;
+/// A wall going left from a corner.
+const LEFT: usize = 4;
+/// A wall going right from a corner.
+const RIGHT: usize = 8;
+
 /// A struct that holds a Grid and a function to format the content of a Cell.
 pub struct GridDisplay<'a> {
     grid: &'a Grid,
     cell_content: Box<dyn Fn(GridCell) -> String + 'a>,
+    cell_width: usize,
+    style: Style,
 }
 
 impl<'a> GridDisplay<'a> {
@@ -20,7 +87,145 @@ impl<'a> GridDisplay<'a> {
     ///
     /// A new `GridDisplay` instance.
     pub fn new(grid: &'a Grid, cell_content: Box<dyn Fn(GridCell) -> String + 'a>) -> Self {
-        Self { grid, cell_content }
+        Self {
+            grid,
+            cell_content,
+            cell_width: DEFAULT_CELL_WIDTH,
+            style: Style::default(),
+        }
+    }
+
+    /// Sets the characters used to draw the walls.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - The style of the walls.
+    ///
+    /// # Returns
+    ///
+    /// The `GridDisplay` instance with the new style.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns whether there is a wall on the west side of the cell at the given position.
+    ///
+    /// The positions outside the grid are used to represent its eastern boundary.
+    fn has_west_wall(&self, row: i32, column: i32) -> bool {
+        if column == 0 || column == self.grid.columns() {
+            return true;
+        }
+
+        let cell = self.grid.cell(row, column).unwrap().borrow();
+
+        !cell.links().contains_key(&(row, column - 1))
+    }
+
+    /// Returns whether there is a wall on the north side of the cell at the given position.
+    ///
+    /// The positions outside the grid are used to represent its southern boundary.
+    fn has_north_wall(&self, row: i32, column: i32) -> bool {
+        if row == 0 || row == self.grid.rows() {
+            return true;
+        }
+
+        let cell = self.grid.cell(row, column).unwrap().borrow();
+
+        !cell.links().contains_key(&(row - 1, column))
+    }
+
+    /// Returns the character drawn at the north west corner of the cell at the given position,
+    /// chosen from the walls meeting at the corner.
+    fn corner(&self, glyphs: &[char; 16], row: i32, column: i32) -> char {
+        let mut walls = 0;
+
+        if row > 0 && self.has_west_wall(row - 1, column) {
+            walls |= UP;
+        }
+
+        if row < self.grid.rows() && self.has_west_wall(row, column) {
+            walls |= DOWN;
+        }
+
+        if column > 0 && self.has_north_wall(row, column - 1) {
+            walls |= LEFT;
+        }
+
+        if column < self.grid.columns() && self.has_north_wall(row, column) {
+            walls |= RIGHT;
+        }
+
+        glyphs[walls]
+    }
+
+    /// Formats the grid with box-drawing characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `glyphs` - The box-drawing characters of the style.
+    ///
+    /// # Returns
+    ///
+    /// The grid drawn with the given characters.
+    fn box_drawing(&self, glyphs: &[char; 16]) -> String {
+        let horizontal = glyphs[LEFT | RIGHT].to_string().repeat(self.cell_width);
+        let vertical = glyphs[UP | DOWN];
+        let passage = " ".repeat(self.cell_width);
+        let mut output = String::new();
+
+        for row in 0..=self.grid.rows() {
+            for column in 0..=self.grid.columns() {
+                output.push(self.corner(glyphs, row, column));
+
+                if column < self.grid.columns() {
+                    if self.has_north_wall(row, column) {
+                        output.push_str(&horizontal);
+                    } else {
+                        output.push_str(&passage);
+                    }
+                }
+            }
+
+            output.push('\n');
+
+            if row == self.grid.rows() {
+                break;
+            }
+
+            for column in 0..=self.grid.columns() {
+                if self.has_west_wall(row, column) {
+                    output.push(vertical);
+                } else {
+                    output.push(' ');
+                }
+
+                if column < self.grid.columns() {
+                    let cell = self.grid.cell(row, column).unwrap().clone();
+                    output.push_str(&(self.cell_content)(cell));
+                }
+            }
+
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Sets the width of the body of every cell.
+    ///
+    /// The `cell_content` closure is expected to return strings of exactly this width.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell_width` - The width, in characters, of the body of a cell.
+    ///
+    /// # Returns
+    ///
+    /// The `GridDisplay` instance with the new cell width.
+    pub fn with_cell_width(mut self, cell_width: usize) -> Self {
+        self.cell_width = cell_width;
+        self
     }
 }
 
@@ -35,10 +240,17 @@ impl<'a> Display for GridDisplay<'a> {
     ///
     /// A `std::fmt::Result` indicating the success or failure of the formatting operation.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(glyphs) = self.style.glyphs() {
+            return write!(f, "{}", self.box_drawing(&glyphs));
+        }
+
         let grid = self.grid as &dyn WithRowsAndColumns;
 
         let mut output = String::from("+");
-        output.push_str(&"---+".repeat(grid.columns() as usize));
+        let wall = "-".repeat(self.cell_width);
+        let passage = " ".repeat(self.cell_width);
+
+        output.push_str(&format!("{wall}+").repeat(grid.columns() as usize));
         output.push('\n');
 
         for row in 0..grid.rows() {
@@ -48,7 +260,7 @@ impl<'a> Display for GridDisplay<'a> {
             for column in 0..grid.columns() {
                 let body = (self.cell_content)(self.grid.cell(row, column).unwrap().clone());
                 let mut east_boundary = String::from("|");
-                let mut south_boundary = String::from("---");
+                let mut south_boundary = wall.clone();
                 let corner = String::from("+");
 
                 if let Some(cell) = grid.cell(row, column) {
@@ -61,7 +273,7 @@ impl<'a> Display for GridDisplay<'a> {
                     }
 
                     if cell.links().contains_key(&south) {
-                        south_boundary = String::from("   ");
+                        south_boundary = passage.clone();
                     }
                 }
 
@@ -82,3 +294,99 @@ impl<'a> Display for GridDisplay<'a> {
         write!(f, "{output}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::test_support::link;
+
+    #[test]
+    fn test_display_rectangular_grid() {
+        let grid = Grid::new(2, 3);
+        let display = GridDisplay::new(&grid, Box::new(|_| String::from("   ")));
+
+        assert_eq!(
+            display.to_string(),
+            "+---+---+---+\n\
+             |   |   |   |\n\
+             +---+---+---+\n\
+             |   |   |   |\n\
+             +---+---+---+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_with_cell_width() {
+        let grid = Grid::new(1, 2);
+        {
+            let first = grid.cell(0, 0).unwrap();
+            first.borrow_mut().link(grid.cell(0, 1).unwrap().clone());
+        }
+
+        let display =
+            GridDisplay::new(&grid, Box::new(|_| String::from(" 10 "))).with_cell_width(4);
+
+        assert_eq!(
+            display.to_string(),
+            "+----+----+\n\
+             | 10   10 |\n\
+             +----+----+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_with_box_drawing_styles() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+
+        let display = |style| {
+            GridDisplay::new(&grid, Box::new(|_| String::from("   ")))
+                .with_style(style)
+                .to_string()
+        };
+
+        assert_eq!(
+            display(Style::Light),
+            "┌───────┐\n\
+             │       │\n\
+             ├───╴   │\n\
+             │       │\n\
+             └───────┘\n"
+        );
+        assert_eq!(
+            display(Style::Heavy),
+            "┏━━━━━━━┓\n\
+             ┃       ┃\n\
+             ┣━━━╸   ┃\n\
+             ┃       ┃\n\
+             ┗━━━━━━━┛\n"
+        );
+        assert_eq!(
+            display(Style::Rounded),
+            "╭───────╮\n\
+             │       │\n\
+             ├───╴   │\n\
+             │       │\n\
+             ╰───────╯\n"
+        );
+    }
+
+    #[test]
+    fn test_display_with_box_drawing_junctions() {
+        let grid = Grid::new(2, 2);
+        let display = GridDisplay::new(&grid, Box::new(|_| String::from("   ")))
+            .with_style(Style::Light)
+            .to_string();
+
+        assert_eq!(
+            display,
+            "┌───┬───┐\n\
+             │   │   │\n\
+             ├───┼───┤\n\
+             │   │   │\n\
+             └───┴───┘\n"
+        );
+    }
+}
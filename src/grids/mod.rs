@@ -1,6 +1,13 @@
 pub mod base_grid;
+pub mod braid;
 pub mod cell;
 pub mod distance_grid;
 pub mod distances;
 pub mod grid;
 pub mod grid_display;
+pub mod path;
+pub mod root;
+pub mod search_grid;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod weighted_grid;
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, fmt::Display};
+
+/// Represents a path through a maze: its cells, in order from the root to the goal, and the
+/// distance of every cell from the root.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    cells: Vec<(i32, i32)>,
+    distances: Vec<i32>,
+    /// The index of every cell of the path, so that a cell is found without scanning the path.
+    indices: HashMap<(i32, i32), usize>,
+}
+
+impl Path {
+    /// Creates a new `Path` from its cells and their distances.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - The cells of the path, from the root to the goal; at least the root.
+    /// * `distances` - The distance from the root of every cell of the path.
+    ///
+    /// # Returns
+    ///
+    /// A new `Path` instance.
+    pub(crate) fn new(cells: Vec<(i32, i32)>, distances: Vec<i32>) -> Self {
+        debug_assert!(!cells.is_empty() && cells.len() == distances.len());
+
+        let indices = cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (*cell, index))
+            .collect();
+
+        Self {
+            cells,
+            distances,
+            indices,
+        }
+    }
+
+    /// Returns the cells of the path, in order from the root to the goal.
+    pub fn cells(&self) -> &[(i32, i32)] {
+        &self.cells
+    }
+
+    /// Returns the cell the path starts from, the root of the distances.
+    pub fn start(&self) -> (i32, i32) {
+        self.cells[0]
+    }
+
+    /// Returns the cell the path ends at.
+    pub fn goal(&self) -> (i32, i32) {
+        self.cells[self.cells.len() - 1]
+    }
+
+    /// Returns the length of the path: the distance from the root to the goal, which is the
+    /// number of passages of the path, or its cost when the cells are weighted.
+    pub fn length(&self) -> i32 {
+        self.distances[self.distances.len() - 1]
+    }
+
+    /// Returns the distance from the root of a cell of the path.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The row and the column of the cell.
+    ///
+    /// # Returns
+    ///
+    /// The distance of the cell, or `None` if the cell is not on the path.
+    pub fn distance(&self, cell: (i32, i32)) -> Option<i32> {
+        self.indices.get(&cell).map(|index| self.distances[*index])
+    }
+
+    /// Returns whether a cell is on the path.
+    pub fn contains(&self, cell: (i32, i32)) -> bool {
+        self.indices.contains_key(&cell)
+    }
+}
+
+/// The errors that may occur while finding a path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError {
+    /// The goal, at the given row and column, has no distance: it cannot be reached from the
+    /// root, or the distances were not calculated.
+    Unreachable((i32, i32)),
+    /// No linked neighbor of the cell, at the given row and column, is closer to the root: the
+    /// distances were not calculated on this maze.
+    Broken((i32, i32)),
+}
+
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::Unreachable((row, column)) => {
+                write!(f, "the cell {row},{column} cannot be reached from the root")
+            }
+            PathError::Broken((row, column)) => write!(
+                f,
+                "no neighbor of the cell {row},{column} is closer to the root"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let path = Path::new(vec![(0, 0), (0, 1), (1, 1)], vec![0, 3, 5]);
+
+        assert_eq!(path.cells(), [(0, 0), (0, 1), (1, 1)]);
+        assert_eq!(path.start(), (0, 0));
+        assert_eq!(path.goal(), (1, 1));
+        assert_eq!(path.length(), 5);
+        assert_eq!(path.distance((0, 1)), Some(3));
+        assert_eq!(path.distance((1, 0)), None);
+        assert!(path.contains((1, 1)));
+        assert!(!path.contains((1, 0)));
+    }
+}
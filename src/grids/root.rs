@@ -0,0 +1,95 @@
+use super::{base_grid::WithRowsAndColumns, distances::Distances};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Selects a cell of a maze, e.g. the root the distances are measured from or the goal of a
+/// solution.
+///
+/// Some cells depend on the maze, like the farthest cell from another one, so a `Root` is only
+/// resolved to a position once the maze is carved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Root {
+    /// The cell at the given row and column.
+    Cell((i32, i32)),
+    /// A random cell, picked with the given seed so that the same cell is picked again.
+    Random(u64),
+    /// The cell in the middle of the grid.
+    Center,
+    /// The cell of the maze farthest from another one.
+    FarthestFrom(Box<Root>),
+}
+
+impl Default for Root {
+    /// The north west cell.
+    fn default() -> Self {
+        Root::Cell((0, 0))
+    }
+}
+
+impl Root {
+    /// Resolves the position of the selected cell in the maze.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The maze in which the cell is selected.
+    ///
+    /// # Returns
+    ///
+    /// The row and the column of the cell, or `None` if the given cell is outside the grid.
+    pub fn resolve(&self, grid: &(impl WithRowsAndColumns + ?Sized)) -> Option<(i32, i32)> {
+        match self {
+            Root::Cell((row, column)) => grid.cell(*row, *column).map(|_| (*row, *column)),
+            Root::Random(seed) => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+
+                Some((
+                    rng.gen_range(0..grid.rows()),
+                    rng.gen_range(0..grid.columns()),
+                ))
+            }
+            Root::Center => Some((grid.rows() / 2, grid.columns() / 2)),
+            Root::FarthestFrom(root) => {
+                let root = root.resolve(grid)?;
+                let mut distances = Distances::new(root);
+                distances.calculate(grid.cell(root.0, root.1)?.clone(), grid);
+
+                Some(distances.max_distance().cell())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::{grid::Grid, test_support::link};
+
+    #[test]
+    fn test_resolve() {
+        let grid = Grid::new(4, 7);
+
+        assert_eq!(Root::default().resolve(&grid), Some((0, 0)));
+        assert_eq!(Root::Cell((3, 6)).resolve(&grid), Some((3, 6)));
+        assert_eq!(Root::Cell((4, 0)).resolve(&grid), None);
+        assert_eq!(Root::Center.resolve(&grid), Some((2, 3)));
+        assert_eq!(
+            Root::Random(9).resolve(&grid),
+            Root::Random(9).resolve(&grid)
+        );
+    }
+
+    #[test]
+    fn test_resolve_farthest_from() {
+        let grid = Grid::new(1, 5);
+
+        for column in 0..4 {
+            link(&grid, (0, column), (0, column + 1));
+        }
+
+        let farthest = |root: Root| Root::FarthestFrom(Box::new(root)).resolve(&grid);
+
+        assert_eq!(farthest(Root::Cell((0, 1))), Some((0, 4)));
+        assert_eq!(farthest(Root::Cell((0, 4))), Some((0, 0)));
+        assert_eq!(farthest(Root::Center), Some((0, 0)));
+        assert_eq!(farthest(Root::Cell((0, 5))), None);
+    }
+}
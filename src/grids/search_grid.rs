@@ -0,0 +1,239 @@
+use super::{
+    base_grid::{GridCell, GridIterator, WithDisplay, WithRowsAndColumns},
+    grid::Grid,
+    grid_display::GridDisplay,
+    root::Root,
+};
+use crate::{
+    solvers::a_star::{AStar, Heuristic, Search},
+    utils::color::Rgb,
+};
+use colored::Colorize;
+use radix_fmt::radix_36;
+use std::collections::{HashMap, HashSet};
+
+/// The color of the cells of the path.
+const PATH_COLOR: Rgb = [230, 120, 20];
+
+/// The color of the cells expanded by the search but not on the path.
+const EXPANDED_COLOR: Rgb = [135, 175, 215];
+
+/// Represents a grid solved with an A* search, displaying the path from the root to the goal and
+/// the cells the search expanded to find it.
+pub struct SearchGrid {
+    grid: Grid,
+    /// The last search, kept so that the grid is displayed without searching again.
+    search: Option<Search>,
+    solver: AStar,
+    root: Root,
+    goal: Root,
+}
+
+impl SearchGrid {
+    /// Creates a new `SearchGrid` with the specified number of rows and columns, solved from the
+    /// north west cell to the south east cell with the Manhattan distance.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows in the grid.
+    /// * `columns` - The number of columns in the grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `SearchGrid` instance.
+    pub fn new(rows: i32, columns: i32) -> Self {
+        Self {
+            grid: Grid::new(rows, columns),
+            search: None,
+            solver: AStar::new(),
+            root: Root::default(),
+            goal: Root::Cell((rows - 1, columns - 1)),
+        }
+    }
+
+    /// Sets the cell the search starts from; the north west cell by default.
+    pub fn with_root(mut self, root: Root) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Sets the cell the search ends at; the south east cell by default.
+    pub fn with_goal(mut self, goal: Root) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    /// Sets the estimate of the distance left to the goal that leads the search.
+    pub fn with_heuristic(mut self, heuristic: Heuristic) -> Self {
+        self.solver = self.solver.with_heuristic(heuristic);
+        self
+    }
+
+    /// Searches the path from the root to the goal; the grid then displays this search until
+    /// the next one.
+    ///
+    /// # Returns
+    ///
+    /// The `Search`, with an empty path if the root or the goal is outside the grid.
+    pub fn search(&mut self) -> &Search {
+        let search = match (self.root.resolve(&self.grid), self.goal.resolve(&self.grid)) {
+            (Some(root), Some(goal)) => self.solver.solve(&self.grid, root, goal),
+            _ => Search::default(),
+        };
+
+        self.search.insert(search)
+    }
+
+    /// Displays the grid with the distances along the path, in base 36, and a dot in every
+    /// other expanded cell, in color or not; the grid is searched first if it never was.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - Whether the path and the expanded cells are filled with their colors.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display_search(&mut self, colors: bool) -> GridDisplay<'_> {
+        if self.search.is_none() {
+            self.search();
+        }
+
+        let search = self.search.as_ref().unwrap();
+        let label_width = format!("{:#}", radix_36(search.path().len().max(1) - 1)).len();
+        let path = search
+            .path()
+            .iter()
+            .enumerate()
+            .map(|(distance, cell)| (*cell, distance))
+            .collect::<HashMap<(i32, i32), usize>>();
+        let expanded = search
+            .expanded()
+            .iter()
+            .copied()
+            .collect::<HashSet<(i32, i32)>>();
+
+        GridDisplay::new(
+            &self.grid,
+            Box::new(move |cell: GridCell| {
+                let cell = cell.borrow().to_row_and_column();
+
+                let (label, [red, green, blue]) = if let Some(distance) = path.get(&cell) {
+                    (format!("{:#}", radix_36(*distance)), PATH_COLOR)
+                } else if expanded.contains(&cell) {
+                    (String::from("."), EXPANDED_COLOR)
+                } else {
+                    return " ".repeat(label_width + 2);
+                };
+
+                let label = format!(" {label:>label_width$} ");
+
+                if colors {
+                    label.as_str().on_truecolor(red, green, blue).to_string()
+                } else {
+                    label
+                }
+            }),
+        )
+        .with_cell_width(label_width + 2)
+    }
+}
+
+impl WithRowsAndColumns for SearchGrid {
+    fn rows(&self) -> i32 {
+        self.grid.rows()
+    }
+
+    fn columns(&self) -> i32 {
+        self.grid.columns()
+    }
+
+    fn cell(&self, row: i32, column: i32) -> Option<&GridCell> {
+        self.grid.cell(row, column)
+    }
+
+    fn iter(&self) -> GridIterator<'_> {
+        self.grid.iter()
+    }
+
+    fn each_row(&self) -> std::slice::Iter<'_, Vec<GridCell>> {
+        self.grid.each_row()
+    }
+}
+
+impl WithDisplay for SearchGrid {
+    /// Displays the grid with the distances along the path and the expanded cells.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display(&mut self) -> GridDisplay<'_> {
+        self.display_search(false)
+    }
+
+    /// Displays the grid with the path and the expanded cells filled with different colors.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display_with_color(&mut self) -> GridDisplay<'_> {
+        self.display_search(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::test_support::fork;
+
+    #[test]
+    fn test_search() {
+        let mut search_grid = fork(SearchGrid::new(2, 3)).with_heuristic(Heuristic::Zero);
+        let search = search_grid.search();
+
+        assert_eq!(search.path(), [(0, 0), (0, 1), (0, 2), (1, 2)]);
+        assert_eq!(search.expanded().len(), 6);
+
+        let mut outside = fork(SearchGrid::new(2, 3)).with_goal(Root::Cell((2, 2)));
+
+        assert!(!outside.search().is_solved());
+    }
+
+    #[test]
+    fn test_display_last_search() {
+        let mut search_grid = fork(SearchGrid::new(2, 3)).with_heuristic(Heuristic::Zero);
+        search_grid.search();
+
+        // Unlinked after the search, the goal is still displayed on the path.
+        let goal = search_grid.cell(1, 2).unwrap().clone();
+        search_grid.cell(0, 2).unwrap().borrow_mut().unlink(goal);
+
+        assert!(search_grid.display().to_string().contains("| 3 |"));
+        assert!(!search_grid.search().is_solved());
+    }
+
+    #[test]
+    fn test_display_search() {
+        let mut search_grid = fork(SearchGrid::new(2, 3)).with_heuristic(Heuristic::Zero);
+
+        assert_eq!(
+            search_grid.display().to_string(),
+            "+---+---+---+\n\
+             | 0   1   2 |\n\
+             +---+   +   +\n\
+             | .   . | 3 |\n\
+             +---+---+---+\n"
+        );
+
+        let mut search_grid = fork(SearchGrid::new(2, 3)).with_heuristic(Heuristic::Manhattan);
+
+        assert_eq!(
+            search_grid.display().to_string(),
+            "+---+---+---+\n\
+             | 0   1   2 |\n\
+             +---+   +   +\n\
+             |       | 3 |\n\
+             +---+---+---+\n"
+        );
+    }
+}
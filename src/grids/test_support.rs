@@ -0,0 +1,42 @@
+use super::base_grid::WithRowsAndColumns;
+
+/// Links two cells of a grid, so that the tests can carve their mazes passage by passage.
+///
+/// # Arguments
+///
+/// * `grid` - The grid the cells belong to.
+/// * `from` - The row and column of the first cell.
+/// * `to` - The row and column of the second cell.
+pub(crate) fn link(grid: &dyn WithRowsAndColumns, from: (i32, i32), to: (i32, i32)) {
+    let other = grid.cell(to.0, to.1).unwrap().clone();
+    grid.cell(from.0, from.1).unwrap().borrow_mut().link(other);
+}
+
+/// Carves a fork in a grid of two rows and three columns: the north row forks at its middle
+/// cell, down to a dead end in the south west cell, and the north east cell opens on the south
+/// east cell.
+///
+/// ```text
+/// +---+---+---+
+/// |           |
+/// +---+   +   +
+/// |       |   |
+/// +---+---+---+
+/// ```
+///
+/// # Arguments
+///
+/// * `grid` - An empty grid of two rows and three columns.
+///
+/// # Returns
+///
+/// The grid, with the fork carved.
+pub(crate) fn fork<T: WithRowsAndColumns>(grid: T) -> T {
+    link(&grid, (0, 0), (0, 1));
+    link(&grid, (0, 1), (0, 2));
+    link(&grid, (0, 1), (1, 1));
+    link(&grid, (1, 1), (1, 0));
+    link(&grid, (0, 2), (1, 2));
+
+    grid
+}
@@ -0,0 +1,317 @@
+use super::{
+    base_grid::{GridCell, GridIterator, WithDisplay, WithRowsAndColumns},
+    distances::Distances,
+    grid::Grid,
+    grid_display::GridDisplay,
+    path::Path,
+    root::Root,
+};
+use crate::utils::color::distance_color;
+use colored::Colorize;
+use rand::{Rng, RngCore};
+
+/// Represents a grid whose cells have a cost, or weight, paid to enter them: a plain floor
+/// costs `1`, while a swamp or a pool of lava costs more.
+///
+/// The distances are the cheapest costs from the root, found with Dijkstra's algorithm, and
+/// every cell is displayed with its cost and its distance, as `cost:distance`.
+pub struct WeightedGrid {
+    grid: Grid,
+    weights: Vec<Vec<i32>>,
+    distances: Distances,
+    path: Option<Path>,
+    root: Root,
+    goal: Option<Root>,
+}
+
+impl WeightedGrid {
+    /// Creates a new `WeightedGrid` with the specified number of rows and columns, where every
+    /// cell costs `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows in the grid.
+    /// * `columns` - The number of columns in the grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `WeightedGrid` instance.
+    pub fn new(rows: i32, columns: i32) -> Self {
+        Self {
+            grid: Grid::new(rows, columns),
+            weights: vec![vec![1; columns as usize]; rows as usize],
+            distances: Distances::new((0, 0)),
+            path: None,
+            root: Root::default(),
+            goal: None,
+        }
+    }
+
+    /// Sets the cell the distances are measured from; the north west cell by default, or when
+    /// the cell is outside the grid.
+    pub fn with_root(mut self, root: Root) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Sets the goal of the cheapest path displayed from the root instead of the distances of
+    /// every cell; with `None`, or when the goal cannot be reached, the distances of every cell
+    /// are displayed.
+    pub fn with_goal(mut self, goal: Option<Root>) -> Self {
+        self.goal = goal;
+        self
+    }
+
+    /// Gives every cell a random cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_weight` - The highest cost of a cell; the costs are between `1` and this one.
+    /// * `rng` - The random number generator used to pick the costs.
+    ///
+    /// # Returns
+    ///
+    /// The `WeightedGrid`, with its new costs.
+    pub fn with_random_weights(mut self, max_weight: i32, rng: &mut dyn RngCore) -> Self {
+        for weight in self.weights.iter_mut().flatten() {
+            *weight = rng.gen_range(1..=max_weight.max(1));
+        }
+
+        self
+    }
+
+    /// Returns the cost of entering a cell; `1` outside the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The row and the column of the cell.
+    pub fn weight(&self, (row, column): (i32, i32)) -> i32 {
+        self.weights
+            .get(row as usize)
+            .and_then(|weights| weights.get(column as usize))
+            .copied()
+            .unwrap_or(1)
+    }
+
+    /// Sets the cost of entering a cell; a cell always costs at least `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The row and the column of the cell.
+    /// * `weight` - The cost of the cell.
+    pub fn set_weight(&mut self, (row, column): (i32, i32), weight: i32) {
+        if let Some(cell) = self
+            .weights
+            .get_mut(row as usize)
+            .and_then(|weights| weights.get_mut(column as usize))
+        {
+            *cell = weight.max(1);
+        }
+    }
+
+    /// Measures the cheapest distances from the root to every cell, and the cheapest path from
+    /// the root to the goal, if any.
+    ///
+    /// # Returns
+    ///
+    /// The measured `Distances`.
+    pub fn distances(&mut self) -> &Distances {
+        let root = self.root.resolve(&self.grid).unwrap_or((0, 0));
+        let cell = self.cell(root.0, root.1).unwrap().to_owned();
+        let weights = &self.weights;
+        let weight = |(row, column): (i32, i32)| weights[row as usize][column as usize];
+
+        self.distances = Distances::new(root);
+        self.distances.calculate_weighted(cell, &self.grid, &weight);
+
+        self.path = self
+            .goal
+            .as_ref()
+            .and_then(|goal| goal.resolve(&self.grid))
+            .and_then(|goal| {
+                let goal = self.cell(goal.0, goal.1).unwrap().to_owned();
+                self.distances.path_to(goal, &self.grid).ok()
+            });
+
+        &self.distances
+    }
+
+    /// Returns the cheapest path from the root to the goal, as of the last measure of the
+    /// distances; `None` without a goal, or when the goal cannot be reached.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_ref()
+    }
+
+    /// Displays the grid with the cost and the distance of every cell, in color or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - Whether the cells are filled with the distance gradient.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display_weights(&mut self, colors: bool) -> GridDisplay<'_> {
+        self.distances();
+
+        let path = self.path.as_ref();
+        let max_distance = path.map_or(self.distances.max_distance().value(), Path::length);
+        let max_weight = self.weights.iter().flatten().max().copied().unwrap_or(1);
+        let weight_width = max_weight.to_string().len();
+        let distance_width = max_distance.to_string().len();
+
+        let distances = &self.distances;
+        let weights = &self.weights;
+
+        GridDisplay::new(
+            &self.grid,
+            Box::new(move |cell: GridCell| {
+                let (row, column) = cell.borrow().to_row_and_column();
+                let weight = weights[row as usize][column as usize];
+
+                let distance = match path {
+                    Some(path) => path.distance((row, column)),
+                    None => distances.get((row, column)).copied(),
+                };
+
+                // Off the path to the goal, only the cost of a cell is shown.
+                let Some(distance) = distance else {
+                    return format!(" {weight:>weight_width$} {:distance_width$} ", "");
+                };
+
+                let label = format!(" {weight:>weight_width$}:{distance:<distance_width$} ");
+
+                if colors {
+                    let [red, green, blue] = distance_color(distance, max_distance);
+                    label.as_str().on_truecolor(red, green, blue).to_string()
+                } else {
+                    label
+                }
+            }),
+        )
+        .with_cell_width(weight_width + distance_width + 3)
+    }
+}
+
+impl WithRowsAndColumns for WeightedGrid {
+    fn rows(&self) -> i32 {
+        self.grid.rows()
+    }
+
+    fn columns(&self) -> i32 {
+        self.grid.columns()
+    }
+
+    fn cell(&self, row: i32, column: i32) -> Option<&GridCell> {
+        self.grid.cell(row, column)
+    }
+
+    fn iter(&self) -> GridIterator<'_> {
+        self.grid.iter()
+    }
+
+    fn each_row(&self) -> std::slice::Iter<'_, Vec<GridCell>> {
+        self.grid.each_row()
+    }
+}
+
+impl WithDisplay for WeightedGrid {
+    /// Displays the grid with the cost and the distance of every cell.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display(&mut self) -> GridDisplay<'_> {
+        self.display_weights(false)
+    }
+
+    /// Displays the grid with the cost and the distance of every cell, filled with colors
+    /// based on the distances.
+    ///
+    /// # Returns
+    ///
+    /// A `GridDisplay` instance that can be used to display the grid.
+    fn display_with_color(&mut self) -> GridDisplay<'_> {
+        self.display_weights(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::test_support::link;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// A loop around a grid of two rows and three columns, with lava in the south west cell.
+    fn lava_loop() -> WeightedGrid {
+        let mut weighted_grid = WeightedGrid::new(2, 3);
+        link(&weighted_grid, (0, 0), (0, 1));
+        link(&weighted_grid, (0, 1), (0, 2));
+        link(&weighted_grid, (0, 2), (1, 2));
+        link(&weighted_grid, (1, 2), (1, 1));
+        link(&weighted_grid, (1, 1), (1, 0));
+        link(&weighted_grid, (1, 0), (0, 0));
+
+        weighted_grid.set_weight((1, 0), 9);
+        weighted_grid
+    }
+
+    #[test]
+    fn test_weights() {
+        let mut weighted_grid = WeightedGrid::new(2, 2);
+
+        assert_eq!(weighted_grid.weight((1, 1)), 1);
+
+        weighted_grid.set_weight((1, 1), 7);
+        weighted_grid.set_weight((0, 1), -3);
+        weighted_grid.set_weight((5, 5), 7);
+
+        assert_eq!(weighted_grid.weight((1, 1)), 7);
+        assert_eq!(weighted_grid.weight((0, 1)), 1);
+        assert_eq!(weighted_grid.weight((5, 5)), 1);
+    }
+
+    #[test]
+    fn test_random_weights() {
+        let weighted_grid =
+            WeightedGrid::new(8, 8).with_random_weights(5, &mut StdRng::seed_from_u64(1));
+        let weights = weighted_grid
+            .iter()
+            .map(|cell| weighted_grid.weight(cell.borrow().to_row_and_column()))
+            .collect::<Vec<i32>>();
+
+        assert!(weights.iter().all(|weight| (1..=5).contains(weight)));
+        assert!(weights.iter().any(|weight| *weight > 1));
+    }
+
+    #[test]
+    fn test_display_costs_and_distances() {
+        let mut weighted_grid = lava_loop();
+
+        assert_eq!(
+            weighted_grid.display().to_string(),
+            "+-----+-----+-----+\n\
+             | 1:0   1:1   1:2 |\n\
+             +     +-----+     +\n\
+             | 9:9   1:4   1:3 |\n\
+             +-----+-----+-----+\n"
+        );
+    }
+
+    #[test]
+    fn test_display_cheapest_path() {
+        let mut weighted_grid = lava_loop()
+            .with_root(Root::Cell((1, 0)))
+            .with_goal(Some(Root::Cell((1, 1))));
+
+        // Leaving the lava is free: only the cells entered are paid.
+        assert_eq!(
+            weighted_grid.display().to_string(),
+            "+-----+-----+-----+\n\
+             | 1     1     1   |\n\
+             +     +-----+     +\n\
+             | 9:0   1:1   1   |\n\
+             +-----+-----+-----+\n"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+//! A Rust version of Jamis Buck's "Mazes for Programmers" algorithms.
+//!
+//! The crate exposes the grids the mazes are carved on, the algorithms that carve them and
+//! the helpers to display them:
+//!
+//! ```
+//! use mazes::{Algorithms, Apply, Grid, WithDisplay};
+//! use mazes::algorithms::binary_tree::BinaryTree;
+//! use rand::{rngs::StdRng, SeedableRng};
+//!
+//! let mut grid = Grid::new(5, 5);
+//! let algorithm = Algorithms::BinaryTree(BinaryTree::new());
+//!
+//! algorithm.apply(&mut grid, &mut StdRng::seed_from_u64(42));
+//!
+//! println!("{}", grid.display());
+//! ```
+pub mod algorithms;
+pub mod analysis;
+pub mod grids;
+pub mod renderers;
+pub mod solvers;
+pub mod utils;
+
+pub use algorithms::{
+    events::{Event, Events},
+    Algorithms, Apply, On,
+};
+pub use analysis::statistics::Statistics;
+pub use grids::{
+    base_grid::{BaseGrid, GridCell, WithDisplay, WithRowsAndColumns},
+    braid::Braid,
+    cell::Cell,
+    distance_grid::DistanceGrid,
+    distances::Distances,
+    grid::Grid,
+    grid_display::{GridDisplay, Style},
+    path::{Path, PathError},
+    root::Root,
+    search_grid::SearchGrid,
+    weighted_grid::WeightedGrid,
+};
+pub use renderers::{
+    animated::{AnimatedRenderer, AnimationFormat, Recording},
+    half_block::HalfBlockDisplay,
+    raster::{Canvas, RasterError, RasterRenderer},
+    svg::SvgRenderer,
+};
+pub use solvers::{
+    a_star::{AStar, Heuristic, Search},
+    dead_end_filling::DeadEndFilling,
+    traversal::{Move, Outcome, Traversal},
+    tremaux::Tremaux,
+    wall_follower::{Hand, WallFollower},
+    Solve, Solvers,
+};
@@ -1,83 +1,558 @@
-#![allow(dead_code, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-mod algorithms;
-mod grids;
-mod utils;
-
-use algorithms::{
-    aldous_broder::AldousBroder, binary_tree::BinaryTree, hunt_and_kill::HuntAndKill,
-    recursive_backtracker::RecursiveBacktracker, sidewinder::Sidewinder, wilsons::Wilsons,
-    Algorithms, Apply,
+mod animation;
+mod cli;
+mod prompt;
+
+use animation::Playback;
+use clap::Parser;
+use cli::{Cli, Format};
+use mazes::{
+    algorithms::ellers::{Ellers, Row},
+    Algorithms, AnimatedRenderer, Apply, BaseGrid, Braid, DistanceGrid, Distances, Event, Grid,
+    HalfBlockDisplay, Heuristic, Outcome, RasterRenderer, Recording, Root, SearchGrid, Solve,
+    Solvers, Statistics, Style, SvgRenderer, WeightedGrid,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::PathBuf,
+    process::ExitCode,
 };
-use grids::{base_grid::BaseGrid, distance_grid::DistanceGrid, grid::Grid};
-use inquire::{validator::Validation, Confirm, CustomType, Select};
-
-pub const MIN_GRID_WIDTH: i32 = 5;
-pub const MAX_GRID_WIDTH: i32 = 11;
-
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let algorithms = vec![
-        Algorithms::BinaryTree(BinaryTree::new()),
-        Algorithms::Sidewinder(Sidewinder::new()),
-        Algorithms::AldousBroder(AldousBroder::new()),
-        Algorithms::Wilsons(Wilsons::new()),
-        Algorithms::HuntAndKill(HuntAndKill::new()),
-        Algorithms::RecursiveBacktracker(RecursiveBacktracker::new()),
-    ];
-
-    let algorithm = Select::new(
-        "Please choose an algorithm to generate the maze:",
-        algorithms,
-    )
-    .prompt()?;
-
-    let width: i32 = CustomType::new("Please choose the width of the grid:")
-        .with_validator(|input: &i32| {
-            if *input < MIN_GRID_WIDTH {
-                return Ok(Validation::Invalid(
-                    format!("Please enter a number greater than {MIN_GRID_WIDTH}").into(),
-                ));
-            }
 
-            if *input > MAX_GRID_WIDTH {
-                return Ok(Validation::Invalid(
-                    format!("Please enter a number lower than {MAX_GRID_WIDTH}").into(),
-                ));
-            }
+pub const MIN_GRID_SIZE: i32 = 2;
+pub const MAX_GRID_SIZE: i32 = 500;
 
-            Ok(Validation::Valid)
-        })
-        .with_help_message(
-            format!("Please enter a number between {MIN_GRID_WIDTH} and {MAX_GRID_WIDTH}",)
-                .as_str(),
-        )
-        .prompt()?;
+/// The options used to generate and display a maze, either prompted or parsed from the command line.
+pub struct Options {
+    algorithm: Algorithms,
+    /// The probability that a dead end is removed.
+    braid: f64,
+    rows: i32,
+    columns: i32,
+    seed: u64,
+    with_distance: bool,
+    with_colors: bool,
+    with_solution: bool,
+    /// Solves the maze like a person in the maze would, instead of finding the shortest path.
+    solver: Option<Solvers>,
+    /// Shows the longest path of the maze, and solves it, instead of the distances from the north
+    /// west cell.
+    longest_path: bool,
+    /// Gives every cell a random cost, up to the given one, and shows the costs and the cheapest
+    /// distances.
+    weights: Option<i32>,
+    /// Solves the maze with an A* search led by the given heuristic, and shows the cells it
+    /// expanded.
+    a_star: Option<Heuristic>,
+    /// Marks the ends of the longest path.
+    markers: bool,
+    /// The cell the distances and the solution start from.
+    root: Root,
+    /// The cell the solution ends at.
+    goal: Root,
+    style: Style,
+    format: Format,
+    raster: RasterRenderer,
+    svg: SvgRenderer,
+    /// The renderer of the animated images.
+    animation: AnimatedRenderer,
+    output: Option<PathBuf>,
+    /// Plays the generation of the maze in the terminal.
+    animate: bool,
+    /// The initial speed of the animation, in steps per second.
+    speed: u32,
+    /// Streams an Eller's maze with the given number of rows, or endlessly when `Some(None)`.
+    stream: Option<Option<u64>>,
+    /// Compares the structure of the algorithms, averaged over the given number of mazes.
+    stats: Option<usize>,
+    /// The algorithms compared by the statistics.
+    compared: Vec<Algorithms>,
+}
 
-    let with_distance =
-        Confirm::new("Would you like to show the distance from the north west cell?")
-            .with_default(false)
-            .prompt()?;
+fn main() -> ExitCode {
+    let interactive = std::env::args_os().len() == 1;
 
-    let with_colors = if with_distance {
-        Confirm::new("Would you like to show the distance with colors?")
-            .with_default(false)
-            .prompt()?
+    let options = if interactive {
+        match prompt::prompt() {
+            Ok(options) => options,
+            Err(error) => {
+                eprintln!("error: {error}");
+                return ExitCode::FAILURE;
+            }
+        }
     } else {
-        false
+        Cli::parse().into_options()
     };
 
-    let mut grid: Box<dyn BaseGrid> = if with_distance {
-        Box::new(DistanceGrid::new(width, width))
+    if let Err(error) = run(&options, interactive) {
+        eprintln!("error: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Generates the maze described by the options and writes it to the chosen output.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate and display the maze.
+/// * `interactive` - Whether the options come from the interactive prompts.
+///
+/// # Returns
+///
+/// An error if the maze cannot be written to the output file.
+fn run(options: &Options, interactive: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(rows) = options.stream {
+        return stream(options, rows);
+    }
+
+    if let Some(mazes) = options.stats {
+        return stats(options, mazes);
+    }
+
+    let empty = Grid::new(options.rows, options.columns);
+
+    for (name, cell) in [("root", &options.root), ("goal", &options.goal)] {
+        if cell.resolve(&empty).is_none() {
+            return Err(format!("the {name} is outside the grid").into());
+        }
+    }
+
+    if let Some(heuristic) = options.a_star {
+        return search(options, heuristic, interactive);
+    }
+
+    let mut grid: Box<dyn BaseGrid> = if let Some(max_weight) = options.weights {
+        // The costs have their own random number generator, so that they do not change the maze.
+        let mut rng = StdRng::seed_from_u64(options.seed.wrapping_add(1));
+
+        Box::new(
+            WeightedGrid::new(options.rows, options.columns)
+                .with_random_weights(max_weight, &mut rng)
+                .with_root(options.root.clone())
+                .with_goal(options.with_solution.then(|| options.goal.clone())),
+        )
+    } else if options.with_distance || options.longest_path {
+        Box::new(
+            DistanceGrid::new(options.rows, options.columns)
+                .with_root(options.root.clone())
+                .with_goal(options.with_solution.then(|| options.goal.clone()))
+                .with_longest_path(options.longest_path)
+                .with_markers(options.markers),
+        )
     } else {
-        Box::new(Grid::new(width, width))
+        Box::new(Grid::new(options.rows, options.columns))
     };
 
-    algorithm.apply(&mut *grid);
+    let mut rng = StdRng::seed_from_u64(options.seed);
+
+    if let Some(format) = options.format.animation() {
+        let mut recording = Recording::new(options.rows, options.columns);
+        recording.record_generation(record(
+            &options.algorithm,
+            options.braid,
+            &mut *grid,
+            &mut rng,
+        ));
+
+        if options.with_distance {
+            let (root, _) = ends(options, &*grid);
+            recording.record_frontiers(&*grid, root);
+        }
+
+        if options.with_solution {
+            let (start, goal) = ends(options, &*grid);
+
+            match &options.solver {
+                Some(solver) => recording.record_traversal(&solver.solve(&*grid, start, goal)),
+                None => recording.record_path(&*grid, start, goal),
+            }
+        }
+
+        eprintln!("Seed: {}", options.seed);
+
+        match &options.output {
+            Some(path) => options.animation.write(
+                &recording,
+                format,
+                std::io::BufWriter::new(fs::File::create(path)?),
+            )?,
+            None => options
+                .animation
+                .write(&recording, format, std::io::stdout().lock())?,
+        }
+
+        return Ok(());
+    }
+
+    if options.animate {
+        let events = record(&options.algorithm, options.braid, &mut *grid, &mut rng);
+        let playback = Playback::new(options.rows, options.columns, events);
+        animation::play(playback, options.style, options.speed)?;
+
+        eprintln!("Seed: {}", options.seed);
+        return Ok(());
+    }
+
+    generate(&options.algorithm, options.braid, &mut *grid, &mut rng);
+    report(options, &*grid, interactive);
+
+    match options.format {
+        Format::Text => {}
+        Format::Gif | Format::Apng => unreachable!("animated images are recorded while generated"),
+        Format::Compact => {
+            let distances = options.with_distance.then(|| distances(options, &*grid));
+            let mut display = HalfBlockDisplay::new(&*grid);
+
+            if let Some(distances) = &distances {
+                display = display.with_distances(distances);
+            }
+
+            let maze = display.to_string();
+
+            match &options.output {
+                Some(path) => fs::write(path, maze)?,
+                None => print!("{maze}"),
+            }
+
+            return Ok(());
+        }
+        Format::Png => {
+            let distances = options.with_distance.then(|| distances(options, &*grid));
+            let canvas = options.raster.render(&*grid, distances.as_ref())?;
+
+            match &options.output {
+                Some(path) => canvas.save_png(path)?,
+                None => canvas.write_png(std::io::stdout().lock())?,
+            }
+
+            return Ok(());
+        }
+        Format::Svg => {
+            let heatmap = options.with_distance.then(|| distances(options, &*grid));
+            let solution = if options.with_solution {
+                let (_, goal) = ends(options, &*grid);
+                let goal = grid.cell(goal.0, goal.1).unwrap().clone();
+
+                // The heatmap is measured from the start of the solution too.
+                let path = match &heatmap {
+                    Some(heatmap) => heatmap.path_to(goal, &*grid)?,
+                    None => distances(options, &*grid).path_to(goal, &*grid)?,
+                };
+
+                Some(path)
+            } else {
+                None
+            };
+
+            let svg = options
+                .svg
+                .render(&*grid, heatmap.as_ref(), solution.as_ref());
+
+            match &options.output {
+                Some(path) => fs::write(path, svg)?,
+                None => print!("{svg}"),
+            }
+
+            return Ok(());
+        }
+    }
+
+    write_text(options, &mut *grid, interactive)
+}
+
+/// Generates the maze, solves it with an A* search and writes it as text, with the path and the
+/// cells the search expanded.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate and display the maze.
+/// * `heuristic` - The heuristic that leads the search.
+/// * `interactive` - Whether the options come from the interactive prompts.
+///
+/// # Returns
+///
+/// An error if the maze cannot be written to the output file.
+fn search(
+    options: &Options,
+    heuristic: Heuristic,
+    interactive: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut grid = SearchGrid::new(options.rows, options.columns)
+        .with_root(options.root.clone())
+        .with_goal(options.goal.clone())
+        .with_heuristic(heuristic);
+
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    generate(&options.algorithm, options.braid, &mut grid, &mut rng);
+    report(options, &grid, interactive);
+
+    let search = grid.search();
+    eprintln!(
+        "Expanded {} of {} cells for a path of {} cells",
+        search.expanded().len(),
+        options.rows * options.columns,
+        search.path().len()
+    );
+
+    write_text(options, &mut grid, interactive)
+}
+
+/// Prints the seed of the maze and, when a solver is chosen, how it solved the maze.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate the maze.
+/// * `grid` - The generated maze.
+/// * `interactive` - Whether the options come from the interactive prompts.
+fn report(options: &Options, grid: &dyn BaseGrid, interactive: bool) {
+    if interactive {
+        println!("\n\nSeed: {}", options.seed);
+    } else {
+        eprintln!("Seed: {}", options.seed);
+    }
 
-    if with_colors {
-        println!("\n\n{}", grid.display_with_color());
+    if let Some(solver) = &options.solver {
+        let (start, goal) = ends(options, grid);
+        let traversal = solver.solve(grid, start, goal);
+        let moves = traversal.moves().len();
+
+        match traversal.outcome() {
+            Outcome::Solved => eprintln!(
+                "{solver}: {moves} moves for a path of {} cells",
+                traversal.path().len()
+            ),
+            Outcome::Lost => eprintln!("{solver}: lost in a loop after {moves} moves"),
+            Outcome::Unreachable => eprintln!("{solver}: no path after {moves} moves"),
+        }
+    }
+}
+
+/// Writes the maze as text, with colors if asked to, to the chosen output.
+///
+/// # Arguments
+///
+/// * `options` - The options used to display the maze.
+/// * `grid` - The generated maze.
+/// * `interactive` - Whether the options come from the interactive prompts.
+///
+/// # Returns
+///
+/// An error if the maze cannot be written to the output file.
+fn write_text(
+    options: &Options,
+    grid: &mut dyn BaseGrid,
+    interactive: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let maze = if options.with_colors {
+        grid.display_with_color()
+            .with_style(options.style)
+            .to_string()
     } else {
-        println!("\n\n{}", grid.display());
+        grid.display().with_style(options.style).to_string()
+    };
+
+    match &options.output {
+        Some(path) => fs::write(path, maze)?,
+        None if interactive => println!("\n{maze}"),
+        None => print!("{maze}"),
+    }
+
+    Ok(())
+}
+
+/// Generates the maze with the chosen algorithm, then braids it if asked to.
+///
+/// # Arguments
+///
+/// * `algorithm` - The algorithm that carves the maze.
+/// * `braid` - The probability that a dead end is removed.
+/// * `grid` - The grid on which to generate the maze.
+/// * `rng` - The random number generator used to carve and braid the maze.
+fn generate(algorithm: &Algorithms, braid: f64, grid: &mut dyn BaseGrid, rng: &mut StdRng) {
+    algorithm.apply(grid, rng);
+
+    if braid > 0.0 {
+        grid.braid(braid, rng);
+    }
+}
+
+/// Generates the maze like `generate`, recording every step of the generation so that it can be
+/// animated.
+///
+/// # Arguments
+///
+/// * `algorithm` - The algorithm that carves the maze.
+/// * `braid` - The probability that a dead end is removed.
+/// * `grid` - The grid on which to generate the maze.
+/// * `rng` - The random number generator used to carve and braid the maze.
+///
+/// # Returns
+///
+/// The events of the generation, followed by the passages carved by the braiding.
+fn record(
+    algorithm: &Algorithms,
+    braid: f64,
+    grid: &mut dyn BaseGrid,
+    rng: &mut StdRng,
+) -> Vec<Event> {
+    let mut events = algorithm.events(grid, rng).collect::<Vec<Event>>();
+
+    if braid > 0.0 {
+        let links = grid.braid(braid, rng);
+        events.extend(links.into_iter().map(|(from, to)| Event::Linked(from, to)));
+    }
+
+    events
+}
+
+/// Prints the structure of the mazes of every compared algorithm, averaged over many mazes.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate the mazes.
+/// * `mazes` - The number of mazes generated per algorithm.
+///
+/// # Returns
+///
+/// An error if the report cannot be written to the output file.
+fn stats(options: &Options, mazes: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let mut report = format!(
+        "{:<45} {:>9} {:>6} {:>9} {:>9} {:>7} {:>8} {:>7} {:>7} {:>10}\n",
+        "Algorithm",
+        "Dead ends",
+        "Ratio",
+        "Junctions",
+        "Corridors",
+        "Turns",
+        "Longest",
+        "Run",
+        "Max run",
+        "Horizontal"
+    );
+
+    for algorithm in &options.compared {
+        let mut rng = StdRng::seed_from_u64(options.seed);
+        let statistics = (0..mazes)
+            .map(|_| {
+                let mut grid = Grid::new(options.rows, options.columns);
+                generate(algorithm, options.braid, &mut grid, &mut rng);
+
+                Statistics::new(&grid)
+            })
+            .sum::<Statistics>();
+
+        report.push_str(&format!(
+            "{:<45} {:>9.1} {:>5.1}% {:>9.1} {:>9.1} {:>7.1} {:>8.1} {:>7.2} {:>7.1} {:>9.1}%\n",
+            algorithm.to_string(),
+            statistics.dead_ends(),
+            statistics.dead_end_ratio() * 100.0,
+            statistics.junctions(),
+            statistics.corridors(),
+            statistics.turns(),
+            statistics.longest_path(),
+            statistics.average_run(),
+            statistics.max_run(),
+            statistics.horizontal_bias() * 100.0,
+        ));
+    }
+
+    eprintln!("Seed: {}", options.seed);
+
+    match &options.output {
+        Some(path) => fs::write(path, report)?,
+        None => print!("{report}"),
     }
 
     Ok(())
 }
+
+/// Streams an Eller's maze to the standard output, printing every row as soon as it is generated.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate the maze.
+/// * `rows` - The number of rows of the maze, or `None` to stream it endlessly.
+///
+/// # Returns
+///
+/// An error if the maze cannot be written to the standard output; a closed pipe, as when the
+/// output is piped to `head`, simply ends the stream.
+fn stream(options: &Options, rows: Option<u64>) -> Result<(), Box<dyn std::error::Error>> {
+    let columns = options.columns as usize;
+    let mut stream = Ellers::new().stream(columns, StdRng::seed_from_u64(options.seed));
+    let mut stdout = std::io::stdout().lock();
+
+    eprintln!("Seed: {}", options.seed);
+
+    let result = (|| {
+        write!(stdout, "{}", Row::header(columns))?;
+
+        match rows {
+            Some(rows) => {
+                for row in stream.by_ref().take(rows as usize - 1) {
+                    write!(stdout, "{row}")?;
+                }
+
+                write!(stdout, "{}", stream.finish())?;
+            }
+            None => {
+                for row in stream {
+                    write!(stdout, "{row}")?;
+                    stdout.flush()?;
+                }
+            }
+        }
+
+        stdout.flush()
+    })();
+
+    match result {
+        Err(error) if error.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Returns the ends of the solution: the ends of the longest path of the maze, or the root and
+/// the goal.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate the maze.
+/// * `grid` - The generated maze.
+///
+/// # Returns
+///
+/// The start and the goal of the solution.
+fn ends(options: &Options, grid: &dyn BaseGrid) -> ((i32, i32), (i32, i32)) {
+    if options.longest_path {
+        let path = Distances::longest_path(grid);
+        (path.start(), path.goal())
+    } else {
+        // Both were checked against the grid before the maze was generated.
+        (
+            options.root.resolve(grid).unwrap(),
+            options.goal.resolve(grid).unwrap(),
+        )
+    }
+}
+
+/// Calculates the distances of every cell of the grid from the root, or from the start of the
+/// longest path.
+///
+/// # Arguments
+///
+/// * `options` - The options used to generate the maze.
+/// * `grid` - The grid on which the distances are calculated.
+///
+/// # Returns
+///
+/// The `Distances` from the root.
+fn distances(options: &Options, grid: &dyn BaseGrid) -> Distances {
+    let (root, _) = ends(options, grid);
+    let mut distances = Distances::new(root);
+    distances.calculate(grid.cell(root.0, root.1).unwrap().clone(), grid);
+
+    distances
+}
@@ -0,0 +1,193 @@
+use crate::{cli::Format, Options, MAX_GRID_SIZE, MIN_GRID_SIZE};
+use inquire::{validator::Validation, Confirm, CustomType, InquireError, Select};
+use mazes::{
+    algorithms::{
+        aldous_broder::AldousBroder,
+        aldous_broder_wilsons::AldousBroderWilsons,
+        binary_tree::BinaryTree,
+        ellers::Ellers,
+        growing_tree::{GrowingTree, Selection},
+        hunt_and_kill::HuntAndKill,
+        kruskals::Kruskals,
+        recursive_backtracker::RecursiveBacktracker,
+        recursive_division::RecursiveDivision,
+        sidewinder::Sidewinder,
+        simplified_prims::SimplifiedPrims,
+        true_prims::TruePrims,
+        wilsons::Wilsons,
+    },
+    Algorithms, AnimatedRenderer, RasterRenderer, Root, Style, SvgRenderer,
+};
+use rand::Rng;
+
+/// Asks the user, through interactive prompts, how the maze should be generated.
+///
+/// # Returns
+///
+/// The `Options` chosen by the user, or an `InquireError` if a prompt fails or is cancelled.
+pub fn prompt() -> Result<Options, InquireError> {
+    let algorithms = vec![
+        Algorithms::BinaryTree(BinaryTree::new()),
+        Algorithms::Sidewinder(Sidewinder::new()),
+        Algorithms::AldousBroder(AldousBroder::new()),
+        Algorithms::Wilsons(Wilsons::new()),
+        Algorithms::HuntAndKill(HuntAndKill::new()),
+        Algorithms::RecursiveBacktracker(RecursiveBacktracker::new()),
+        Algorithms::GrowingTree(GrowingTree::new(Selection::Newest)),
+        Algorithms::Kruskals(Kruskals::new()),
+        Algorithms::SimplifiedPrims(SimplifiedPrims::new()),
+        Algorithms::TruePrims(TruePrims::new()),
+        Algorithms::Ellers(Ellers::new()),
+        Algorithms::RecursiveDivision(RecursiveDivision::new()),
+        Algorithms::AldousBroderWilsons(AldousBroderWilsons::new(0.5)),
+    ];
+
+    let mut algorithm = Select::new(
+        "Please choose an algorithm to generate the maze:",
+        algorithms,
+    )
+    .prompt()?;
+
+    if let Algorithms::GrowingTree(_) = algorithm {
+        let selections = vec![
+            Selection::Newest,
+            Selection::Oldest,
+            Selection::Random,
+            Selection::Mixed(vec![(Selection::Newest, 75), (Selection::Random, 25)]),
+            Selection::Mixed(vec![(Selection::Newest, 50), (Selection::Oldest, 50)]),
+        ];
+
+        let selection = Select::new(
+            "Please choose how the next active cell is selected:",
+            selections,
+        )
+        .prompt()?;
+
+        algorithm = Algorithms::GrowingTree(GrowingTree::new(selection));
+    }
+
+    if let Algorithms::RecursiveDivision(_) = algorithm {
+        let room_size: i32 =
+            CustomType::new("Please choose the size below which regions are left as open rooms:")
+                .with_default(0)
+                .with_help_message("Enter 0 for a maze without rooms")
+                .prompt()?;
+
+        algorithm =
+            Algorithms::RecursiveDivision(RecursiveDivision::new().with_room_size(room_size));
+    }
+
+    if let Algorithms::AldousBroderWilsons(_) = algorithm {
+        let fraction: f64 = CustomType::new(
+            "Please choose the fraction of the cells visited before switching to Wilson's:",
+        )
+        .with_default(0.5)
+        .with_validator(|input: &f64| {
+            if !(0.0..=1.0).contains(input) {
+                return Ok(Validation::Invalid(
+                    "Please enter a number between 0 and 1".into(),
+                ));
+            }
+
+            Ok(Validation::Valid)
+        })
+        .prompt()?;
+
+        algorithm = Algorithms::AldousBroderWilsons(AldousBroderWilsons::new(fraction));
+    }
+
+    let rows = grid_size("Please choose the number of rows of the grid:")?;
+    let columns = grid_size("Please choose the number of columns of the grid:")?;
+
+    let with_distance =
+        Confirm::new("Would you like to show the distance from the north west cell?")
+            .with_default(false)
+            .prompt()?;
+
+    let longest_path = with_distance
+        && Confirm::new("Would you like to show the longest path of the maze instead?")
+            .with_default(false)
+            .with_help_message("Its ends, the start and the goal, are marked with s and g")
+            .prompt()?;
+
+    let with_colors = if with_distance {
+        Confirm::new("Would you like to show the distance with colors?")
+            .with_default(false)
+            .prompt()?
+    } else {
+        false
+    };
+
+    let style = Select::new("Please choose the style of the walls:", Style::all()).prompt()?;
+
+    let animate = Confirm::new("Would you like to watch the maze being generated?")
+        .with_default(false)
+        .with_help_message("Space pauses, n steps while paused, + and - change the speed")
+        .prompt()?;
+
+    let seed: u64 = CustomType::new("Please choose the seed of the maze:")
+        .with_default(rand::thread_rng().gen())
+        .with_help_message("Reuse a previous seed to generate the same maze again")
+        .prompt()?;
+
+    Ok(Options {
+        algorithm,
+        braid: 0.0,
+        rows,
+        columns,
+        seed,
+        with_distance,
+        with_colors,
+        with_solution: false,
+        longest_path,
+        weights: None,
+        a_star: None,
+        solver: None,
+        markers: longest_path,
+        root: Root::default(),
+        goal: Root::Cell((rows - 1, columns - 1)),
+        style,
+        format: Format::Text,
+        raster: RasterRenderer::new(),
+        svg: SvgRenderer::new(),
+        animation: AnimatedRenderer::new(),
+        output: None,
+        animate,
+        speed: 30,
+        stream: None,
+        stats: None,
+        compared: vec![],
+    })
+}
+
+/// Asks the user for a size of the grid, within the allowed range.
+///
+/// # Arguments
+///
+/// * `message` - The message of the prompt.
+///
+/// # Returns
+///
+/// The size chosen by the user.
+fn grid_size(message: &str) -> Result<i32, InquireError> {
+    CustomType::new(message)
+        .with_validator(|input: &i32| {
+            if *input < MIN_GRID_SIZE {
+                return Ok(Validation::Invalid(
+                    format!("Please enter a number greater than {MIN_GRID_SIZE}").into(),
+                ));
+            }
+
+            if *input > MAX_GRID_SIZE {
+                return Ok(Validation::Invalid(
+                    format!("Please enter a number lower than {MAX_GRID_SIZE}").into(),
+                ));
+            }
+
+            Ok(Validation::Valid)
+        })
+        .with_help_message(
+            format!("Please enter a number between {MIN_GRID_SIZE} and {MAX_GRID_SIZE}",).as_str(),
+        )
+        .prompt()
+}
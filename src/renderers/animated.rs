@@ -0,0 +1,632 @@
+use super::raster::{Canvas, RasterError, RasterRenderer};
+use crate::{
+    algorithms::events::Event,
+    grids::{base_grid::WithRowsAndColumns, distances::Distances, grid::Grid},
+    solvers::traversal::{Move, Traversal},
+    utils::color::{distance_color, Rgb},
+};
+use std::{collections::HashMap, fmt::Display, io::Write};
+
+/// The color of the cell a generator is on.
+const CURRENT_COLOR: Rgb = [220, 50, 47];
+
+/// The color of the cells of the solution.
+const PATH_COLOR: Rgb = [230, 120, 20];
+
+/// The color of the cells a solver walked through.
+const WALKED_COLOR: Rgb = [135, 175, 215];
+
+/// The color of the cells filled by a solver.
+const FILLED_COLOR: Rgb = [120, 120, 120];
+
+/// How long, in milliseconds, the last frame is shown before the animation loops.
+const FINAL_FRAME_DELAY: u16 = 2000;
+
+/// A step of a recorded animation.
+#[derive(Debug, Clone, PartialEq)]
+enum Step {
+    /// A step of the generation of the maze.
+    Generation(Event),
+    /// A frontier of the breadth-first search from the root, at the given distance.
+    Frontier(i32, Vec<(i32, i32)>),
+    /// A cell of the path from the goal back to the root.
+    Trace((i32, i32)),
+    /// A move of a solver.
+    Solver(Move),
+}
+
+/// The sequence of steps of an animation: the generation of a maze and, optionally, how it
+/// is solved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    rows: i32,
+    columns: i32,
+    steps: Vec<Step>,
+}
+
+impl Recording {
+    /// Creates an empty recording for a grid of the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows of the grid.
+    /// * `columns` - The number of columns of the grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `Recording` instance.
+    pub fn new(rows: i32, columns: i32) -> Self {
+        Self {
+            rows,
+            columns,
+            steps: vec![],
+        }
+    }
+
+    /// Returns the number of recorded steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns whether no step has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Records the events of the generation of a maze.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - The events reported by a generator, e.g. by `On::events`.
+    pub fn record_generation(&mut self, events: impl IntoIterator<Item = Event>) {
+        self.steps.extend(events.into_iter().map(Step::Generation));
+    }
+
+    /// Records the frontiers of the breadth-first search of `Distances::calculate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The generated maze.
+    /// * `root` - The cell the distances are calculated from.
+    pub fn record_frontiers(&mut self, grid: &dyn WithRowsAndColumns, root: (i32, i32)) {
+        let Some(cell) = grid.cell(root.0, root.1) else {
+            return;
+        };
+
+        let mut distance = 0;
+        let mut distances = Distances::new(root);
+
+        distances.calculate_observed(cell.clone(), grid, &mut |frontier| {
+            self.steps.push(Step::Frontier(distance, frontier.to_vec()));
+            distance += 1;
+        });
+    }
+
+    /// Records the trace of `Distances::path_to`, from the goal back to the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The generated maze.
+    /// * `root` - The start of the path.
+    /// * `goal` - The end of the path.
+    pub fn record_path(
+        &mut self,
+        grid: &dyn WithRowsAndColumns,
+        root: (i32, i32),
+        goal: (i32, i32),
+    ) {
+        let (Some(start), Some(end)) = (grid.cell(root.0, root.1), grid.cell(goal.0, goal.1))
+        else {
+            return;
+        };
+
+        let mut distances = Distances::new(root);
+        distances.calculate(start.clone(), grid);
+
+        let Ok(path) = distances.path_to(end.clone(), grid) else {
+            return;
+        };
+
+        self.steps
+            .extend(path.cells().iter().rev().copied().map(Step::Trace));
+    }
+
+    /// Records the moves of a solver, then the path it found, from the start to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `traversal` - The traversal returned by a solver, e.g. by `Solve::solve`.
+    pub fn record_traversal(&mut self, traversal: &Traversal) {
+        self.steps
+            .extend(traversal.moves().iter().copied().map(Step::Solver));
+        self.steps
+            .extend(traversal.path().iter().copied().map(Step::Trace));
+    }
+}
+
+/// The formats an animation can be encoded in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationFormat {
+    /// An animated GIF, limited to 256 colors per frame.
+    Gif,
+    /// An animated PNG.
+    Apng,
+}
+
+/// The errors that may occur while encoding an animation.
+#[derive(Debug)]
+pub enum AnimationError {
+    /// The image is too large for the format.
+    TooLarge(u64, u64),
+    /// The frames are too large to be drawn.
+    Raster(RasterError),
+    /// The GIF image cannot be encoded or written.
+    Gif(gif::EncodingError),
+    /// The PNG image cannot be encoded or written.
+    Png(::png::EncodingError),
+}
+
+impl Display for AnimationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimationError::TooLarge(width, height) => write!(
+                f,
+                "a {width}x{height} image is too large for an animated GIF"
+            ),
+            AnimationError::Raster(error) => write!(f, "{error}"),
+            AnimationError::Gif(error) => write!(f, "{error}"),
+            AnimationError::Png(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for AnimationError {}
+
+impl From<RasterError> for AnimationError {
+    fn from(error: RasterError) -> Self {
+        AnimationError::Raster(error)
+    }
+}
+
+impl From<gif::EncodingError> for AnimationError {
+    fn from(error: gif::EncodingError) -> Self {
+        AnimationError::Gif(error)
+    }
+}
+
+impl From<::png::EncodingError> for AnimationError {
+    fn from(error: ::png::EncodingError) -> Self {
+        AnimationError::Png(error)
+    }
+}
+
+/// Draws the frames of a `Recording` and encodes them as an animated GIF or PNG.
+///
+/// Every frame replays a given number of steps; the frames are drawn one at a time while they
+/// are encoded, so that long animations are never held in memory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimatedRenderer {
+    raster: RasterRenderer,
+    frame_delay: u16,
+    steps_per_frame: usize,
+}
+
+impl Default for AnimatedRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimatedRenderer {
+    /// Creates a new renderer, with a frame every 40 milliseconds and a step per frame.
+    ///
+    /// # Returns
+    ///
+    /// A new `AnimatedRenderer` instance.
+    pub fn new() -> Self {
+        Self {
+            raster: RasterRenderer::new(),
+            frame_delay: 40,
+            steps_per_frame: 1,
+        }
+    }
+
+    /// Sets the renderer used to draw every frame, with its cell size and colors.
+    pub fn with_raster(mut self, raster: RasterRenderer) -> Self {
+        self.raster = raster;
+        self
+    }
+
+    /// Sets how long, in milliseconds, every frame is shown.
+    ///
+    /// GIF images count the delay in hundredths of a second, so it is rounded down to a
+    /// multiple of 10 milliseconds.
+    pub fn with_frame_delay(mut self, frame_delay: u16) -> Self {
+        self.frame_delay = frame_delay;
+        self
+    }
+
+    /// Sets how many recorded steps every frame replays; at least one.
+    pub fn with_steps_per_frame(mut self, steps_per_frame: usize) -> Self {
+        self.steps_per_frame = steps_per_frame.max(1);
+        self
+    }
+
+    /// Returns the number of frames of the animation of the recording.
+    pub fn frame_count(&self, recording: &Recording) -> usize {
+        recording.len().div_ceil(self.steps_per_frame).max(1)
+    }
+
+    /// Returns an iterator over the frames of the animation of the recording, or an error if
+    /// the frames are too large to be drawn.
+    pub fn frames<'a>(&'a self, recording: &'a Recording) -> Result<Frames<'a>, RasterError> {
+        let grid = Grid::new(recording.rows, recording.columns);
+
+        Ok(Frames {
+            renderer: self,
+            recording,
+            blank: self.raster.canvas(&grid)?,
+            grid,
+            position: 0,
+            current: None,
+            distances: HashMap::new(),
+            solver: HashMap::new(),
+            path: vec![],
+            remaining: self.frame_count(recording),
+        })
+    }
+
+    /// Encodes the animation of the recording.
+    ///
+    /// # Arguments
+    ///
+    /// * `recording` - The recorded steps.
+    /// * `format` - The format of the animation.
+    /// * `writer` - The writer the animation is written to.
+    ///
+    /// # Returns
+    ///
+    /// An error if the animation cannot be encoded or written.
+    pub fn write(
+        &self,
+        recording: &Recording,
+        format: AnimationFormat,
+        writer: impl Write,
+    ) -> Result<(), AnimationError> {
+        match format {
+            AnimationFormat::Gif => self.write_gif(recording, writer),
+            AnimationFormat::Apng => self.write_apng(recording, writer),
+        }
+    }
+
+    /// Encodes the animation of the recording as an animated GIF, which loops forever.
+    pub fn write_gif(
+        &self,
+        recording: &Recording,
+        writer: impl Write,
+    ) -> Result<(), AnimationError> {
+        let grid = Grid::new(recording.rows, recording.columns);
+        let (canvas_width, canvas_height) = self.raster.canvas_size(&grid);
+        let (Ok(width), Ok(height)) = (u16::try_from(canvas_width), u16::try_from(canvas_height))
+        else {
+            return Err(AnimationError::TooLarge(canvas_width, canvas_height));
+        };
+
+        let mut encoder = gif::Encoder::new(writer, width, height, &[])?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        let count = self.frame_count(recording);
+
+        for (index, canvas) in self.frames(recording)?.enumerate() {
+            let mut frame = gif::Frame::from_rgb_speed(width, height, canvas.pixels(), 10);
+            frame.delay = self.delay(index, count) / 10;
+            encoder.write_frame(&frame)?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the animation of the recording as an animated PNG, which loops forever.
+    pub fn write_apng(
+        &self,
+        recording: &Recording,
+        writer: impl Write,
+    ) -> Result<(), AnimationError> {
+        let frames = self.frames(recording)?;
+        let (width, height) = (frames.blank.width(), frames.blank.height());
+        let count = self.frame_count(recording);
+
+        let mut encoder = ::png::Encoder::new(writer, width, height);
+        encoder.set_color(::png::ColorType::Rgb);
+        encoder.set_depth(::png::BitDepth::Eight);
+        encoder.set_animated(count as u32, 0)?;
+
+        let mut writer = encoder.write_header()?;
+
+        for (index, canvas) in frames.enumerate() {
+            writer.set_frame_delay(self.delay(index, count), 1000)?;
+            writer.write_image_data(canvas.pixels())?;
+        }
+
+        writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Returns the delay, in milliseconds, of a frame: the last one lasts longer.
+    fn delay(&self, index: usize, count: usize) -> u16 {
+        if index + 1 == count {
+            self.frame_delay.max(FINAL_FRAME_DELAY)
+        } else {
+            self.frame_delay
+        }
+    }
+}
+
+/// An iterator over the frames of an animation, which replays the recorded steps on a fresh
+/// grid.
+pub struct Frames<'a> {
+    renderer: &'a AnimatedRenderer,
+    recording: &'a Recording,
+    /// An empty frame, copied before every frame is drawn.
+    blank: Canvas,
+    grid: Grid,
+    position: usize,
+    current: Option<(i32, i32)>,
+    distances: HashMap<(i32, i32), i32>,
+    solver: HashMap<(i32, i32), Rgb>,
+    path: Vec<(i32, i32)>,
+    remaining: usize,
+}
+
+impl Frames<'_> {
+    /// Replays a recorded step on the grid.
+    fn step(&mut self, step: &Step) {
+        match step {
+            Step::Generation(Event::Visited(cell)) => self.current = Some(*cell),
+            Step::Generation(Event::Linked(from, to)) => {
+                let other = self.grid.cell(to.0, to.1).unwrap().clone();
+                self.grid
+                    .cell(from.0, from.1)
+                    .unwrap()
+                    .borrow_mut()
+                    .link(other);
+            }
+            Step::Generation(Event::Unlinked(from, to)) => {
+                let other = self.grid.cell(to.0, to.1).unwrap().clone();
+                self.grid
+                    .cell(from.0, from.1)
+                    .unwrap()
+                    .borrow_mut()
+                    .unlink(other);
+            }
+            Step::Generation(_) => {}
+            Step::Frontier(distance, cells) => {
+                self.current = None;
+
+                for cell in cells {
+                    self.distances.insert(*cell, *distance);
+                }
+            }
+            Step::Trace(cell) => {
+                self.current = None;
+                self.path.push(*cell);
+            }
+            Step::Solver(Move::Entered(cell) | Move::Backtracked(cell)) => {
+                self.current = Some(*cell);
+                self.solver.insert(*cell, WALKED_COLOR);
+            }
+            Step::Solver(Move::Filled(cell)) => {
+                self.current = None;
+                self.solver.insert(*cell, FILLED_COLOR);
+            }
+        }
+    }
+
+    /// Draws the grid as it is.
+    fn draw(&self) -> Canvas {
+        let raster = &self.renderer.raster;
+        let mut canvas = self.blank.clone();
+        let max_distance = self.distances.values().copied().max().unwrap_or(0);
+
+        for (cell, distance) in &self.distances {
+            raster.fill_cell(&mut canvas, *cell, distance_color(*distance, max_distance));
+        }
+
+        for (cell, color) in &self.solver {
+            raster.fill_cell(&mut canvas, *cell, *color);
+        }
+
+        for cell in &self.path {
+            raster.fill_cell(&mut canvas, *cell, PATH_COLOR);
+        }
+
+        if let Some(cell) = self.current {
+            raster.fill_cell(&mut canvas, cell, CURRENT_COLOR);
+        }
+
+        raster.draw_walls(&mut canvas, &self.grid);
+
+        canvas
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = Canvas;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+
+        let end = (self.position + self.renderer.steps_per_frame).min(self.recording.len());
+        let recording = self.recording;
+
+        for step in &recording.steps[self.position..end] {
+            self.step(step);
+        }
+
+        self.position = end;
+
+        // The generator leaves the maze once every step is replayed.
+        if self.remaining == 0 {
+            self.current = None;
+        }
+
+        Some(self.draw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, On},
+        solvers::{dead_end_filling::DeadEndFilling, Solve},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn recording() -> (Grid, Recording) {
+        let mut grid = Grid::new(4, 5);
+        let mut recording = Recording::new(4, 5);
+
+        recording.record_generation(
+            RecursiveBacktracker::new().events(&mut grid, &mut StdRng::seed_from_u64(4)),
+        );
+
+        (grid, recording)
+    }
+
+    #[test]
+    fn test_frames() {
+        let (grid, recording) = recording();
+        let renderer = AnimatedRenderer::new().with_steps_per_frame(10);
+        let frames = renderer
+            .frames(&recording)
+            .unwrap()
+            .collect::<Vec<Canvas>>();
+
+        // Every cell is visited, linked (but the first) and backtracked.
+        assert_eq!(recording.len(), 3 * 4 * 5 - 1);
+        assert_eq!(frames.len(), renderer.frame_count(&recording));
+        assert_eq!(frames.len(), 6);
+
+        // The last frame shows the whole maze.
+        assert_eq!(
+            frames.last(),
+            Some(&RasterRenderer::new().render(&grid, None).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_frames_with_solution() {
+        let (grid, mut recording) = recording();
+        let generation = recording.len();
+
+        recording.record_frontiers(&grid, (0, 0));
+        let frontiers = recording.len() - generation;
+        recording.record_path(&grid, (0, 0), (3, 4));
+
+        let raster = RasterRenderer::new();
+        let renderer = AnimatedRenderer::new().with_raster(raster.clone());
+        let last = renderer.frames(&recording).unwrap().last().unwrap();
+        let center = |row, column| {
+            let (x, y) = raster.cell_origin(row, column);
+            last.pixel(x + 10, y + 10)
+        };
+
+        let root = grid.cell(0, 0).unwrap().clone();
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(root, &grid);
+
+        // A frontier per distance, then a step per cell of the path.
+        assert_eq!(frontiers as i32, distances.max_distance().value() + 1);
+        assert_eq!(
+            (recording.len() - generation - frontiers) as i32,
+            distances.get((3, 4)).unwrap() + 1
+        );
+        assert_eq!(center(0, 0), Some(PATH_COLOR));
+        assert_eq!(center(3, 4), Some(PATH_COLOR));
+    }
+
+    #[test]
+    fn test_frames_with_traversal() {
+        let (grid, mut recording) = recording();
+        let generation = recording.len();
+
+        let traversal = DeadEndFilling::new().solve(&grid, (0, 0), (3, 4));
+        recording.record_traversal(&traversal);
+
+        let raster = RasterRenderer::new();
+        let renderer = AnimatedRenderer::new().with_raster(raster.clone());
+        let last = renderer.frames(&recording).unwrap().last().unwrap();
+        let center = |(row, column)| {
+            let (x, y) = raster.cell_origin(row, column);
+            last.pixel(x + 10, y + 10)
+        };
+
+        // A step per move, then a step per cell of the path.
+        assert_eq!(
+            recording.len() - generation,
+            traversal.moves().len() + traversal.path().len()
+        );
+        assert!(traversal
+            .path()
+            .iter()
+            .all(|cell| center(*cell) == Some(PATH_COLOR)));
+        assert!(traversal.moves().iter().all(|step| match step {
+            Move::Filled(cell) => center(*cell) == Some(FILLED_COLOR),
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn test_empty_recording() {
+        let recording = Recording::new(2, 2);
+        let renderer = AnimatedRenderer::new();
+
+        assert!(recording.is_empty());
+        assert_eq!(renderer.frames(&recording).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_write_gif() {
+        let (_, recording) = recording();
+        let mut gif = vec![];
+
+        AnimatedRenderer::new()
+            .with_steps_per_frame(20)
+            .write(&recording, AnimationFormat::Gif, &mut gif)
+            .unwrap();
+
+        assert!(gif.starts_with(b"GIF89a"));
+    }
+
+    #[test]
+    fn test_write_apng() {
+        let (_, recording) = recording();
+        let mut png = vec![];
+
+        AnimatedRenderer::new()
+            .with_steps_per_frame(20)
+            .write(&recording, AnimationFormat::Apng, &mut png)
+            .unwrap();
+
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(png.windows(4).any(|chunk| chunk == b"acTL"));
+    }
+
+    #[test]
+    fn test_gif_too_large() {
+        let recording = Recording::new(500, 500);
+        let renderer =
+            AnimatedRenderer::new().with_raster(RasterRenderer::new().with_cell_size(200));
+
+        assert!(matches!(
+            renderer.write_gif(&recording, vec![]),
+            Err(AnimationError::TooLarge(_, _))
+        ));
+    }
+}
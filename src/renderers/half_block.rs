@@ -0,0 +1,225 @@
+use crate::{
+    grids::{base_grid::WithRowsAndColumns, distances::Distances},
+    utils::color::{distance_color, Rgb},
+};
+use colored::Colorize;
+use std::fmt::Display;
+
+/// The quadrant block characters, indexed by the filled quadrants: `TOP_LEFT`, `TOP_RIGHT`,
+/// `BOTTOM_LEFT` and `BOTTOM_RIGHT`, combined as a bitmask.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+const TOP_LEFT: usize = 1;
+const TOP_RIGHT: usize = 2;
+const BOTTOM_LEFT: usize = 4;
+
+/// A compact display of a grid, which draws every cell in a single character.
+///
+/// The maze is seen as a bitmap where every cell, every wall and every corner is a pixel;
+/// each character packs four of those pixels with the quadrant block characters: the north
+/// west corner of a cell, its northern wall, its western wall and the cell itself.
+/// A maze with `columns` columns then fits in `columns + 1` terminal columns.
+pub struct HalfBlockDisplay<'a> {
+    grid: &'a dyn WithRowsAndColumns,
+    distances: Option<&'a Distances>,
+}
+
+impl<'a> HalfBlockDisplay<'a> {
+    /// Creates a new `HalfBlockDisplay` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - A reference to the grid.
+    ///
+    /// # Returns
+    ///
+    /// A new `HalfBlockDisplay` instance.
+    pub fn new(grid: &'a dyn WithRowsAndColumns) -> Self {
+        Self {
+            grid,
+            distances: None,
+        }
+    }
+
+    /// Colors the background of every cell with the distance gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `distances` - The distances of the cells from a root cell.
+    ///
+    /// # Returns
+    ///
+    /// The `HalfBlockDisplay` instance with the distances.
+    pub fn with_distances(mut self, distances: &'a Distances) -> Self {
+        self.distances = Some(distances);
+        self
+    }
+
+    /// Returns whether there is a wall on the west side of the cell at the given position.
+    ///
+    /// The positions outside the grid are used to represent its southern and eastern boundary.
+    fn has_west_wall(&self, row: i32, column: i32) -> bool {
+        if row == self.grid.rows() {
+            return false;
+        }
+
+        if column == 0 || column == self.grid.columns() {
+            return true;
+        }
+
+        let cell = self.grid.cell(row, column).unwrap().borrow();
+
+        !cell.links().contains_key(&(row, column - 1))
+    }
+
+    /// Returns whether there is a wall on the north side of the cell at the given position.
+    ///
+    /// The positions outside the grid are used to represent its southern and eastern boundary.
+    fn has_north_wall(&self, row: i32, column: i32) -> bool {
+        if column == self.grid.columns() {
+            return false;
+        }
+
+        if row == 0 || row == self.grid.rows() {
+            return true;
+        }
+
+        let cell = self.grid.cell(row, column).unwrap().borrow();
+
+        !cell.links().contains_key(&(row - 1, column))
+    }
+
+    /// Returns the character drawn for the cell at the given position.
+    fn block(&self, row: i32, column: i32) -> char {
+        let north = self.has_north_wall(row, column);
+        let west = self.has_west_wall(row, column);
+        let corner = north
+            || west
+            || (row > 0 && self.has_west_wall(row - 1, column))
+            || (column > 0 && self.has_north_wall(row, column - 1));
+
+        let mut quadrants = 0;
+
+        if corner {
+            quadrants |= TOP_LEFT;
+        }
+
+        if north {
+            quadrants |= TOP_RIGHT;
+        }
+
+        if west {
+            quadrants |= BOTTOM_LEFT;
+        }
+
+        QUADRANTS[quadrants]
+    }
+
+    /// Returns the background color of the cell at the given position, if it has a distance.
+    fn color(&self, row: i32, column: i32, max_distance: i32) -> Option<Rgb> {
+        self.distances
+            .and_then(|distances| distances.get((row, column)))
+            .map(|distance| distance_color(*distance, max_distance))
+    }
+}
+
+impl<'a> Display for HalfBlockDisplay<'a> {
+    /// Formats the `HalfBlockDisplay` instance for display.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The formatter to write the output to.
+    ///
+    /// # Returns
+    ///
+    /// A `std::fmt::Result` indicating the success or failure of the formatting operation.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_distance = self
+            .distances
+            .map_or(0, |distances| distances.max_distance().value());
+
+        let mut output = String::new();
+
+        for row in 0..=self.grid.rows() {
+            for column in 0..=self.grid.columns() {
+                let block = self.block(row, column).to_string();
+
+                match self.color(row, column, max_distance) {
+                    Some([red, green, blue]) => {
+                        output.push_str(&block.on_truecolor(red, green, blue).to_string());
+                    }
+                    None => output.push_str(&block),
+                }
+            }
+
+            output.push('\n');
+        }
+
+        write!(f, "{output}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::{grid::Grid, test_support::link};
+
+    #[test]
+    fn test_display_closed_grid() {
+        let grid = Grid::new(2, 3);
+        let display = HalfBlockDisplay::new(&grid).to_string();
+
+        assert_eq!(
+            display,
+            "▛▛▛▌\n\
+             ▛▛▛▌\n\
+             ▀▀▀▘\n"
+        );
+    }
+
+    #[test]
+    fn test_display_linked_grid() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+
+        let display = HalfBlockDisplay::new(&grid).to_string();
+
+        assert_eq!(
+            display,
+            "▛▀▌\n\
+             ▛▘▌\n\
+             ▀▀▘\n"
+        );
+    }
+
+    #[test]
+    fn test_display_large_grid_width() {
+        let grid = Grid::new(150, 150);
+        let display = HalfBlockDisplay::new(&grid).to_string();
+
+        assert_eq!(display.lines().count(), 151);
+        assert!(display.lines().all(|line| line.chars().count() == 151));
+    }
+
+    #[test]
+    fn test_display_with_distances() {
+        let grid = Grid::new(1, 2);
+        let root = grid.cell(0, 0).unwrap().clone();
+        root.borrow_mut().link(grid.cell(0, 1).unwrap().clone());
+
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(root, &grid);
+
+        let display = HalfBlockDisplay::new(&grid).with_distances(&distances);
+
+        // The colors are checked without forcing them on the terminal, which every test shares.
+        assert_eq!(display.color(0, 0, 1), Some([255, 255, 255]));
+        assert_eq!(display.color(0, 1, 1), Some([0, 0, 128]));
+        assert_eq!(display.color(1, 0, 1), None);
+        assert!(display.to_string().starts_with("▛▀▌"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod animated;
+pub mod half_block;
+pub mod raster;
+pub mod svg;
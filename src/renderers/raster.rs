@@ -0,0 +1,452 @@
+use crate::{
+    grids::{base_grid::WithRowsAndColumns, distances::Distances},
+    utils::color::{distance_color, Rgb},
+};
+use std::{fmt::Display, fs::File, io::BufWriter, io::Write, path::Path};
+
+/// The largest number of pixels of a canvas, about a billion, which take 3 GiB.
+pub const MAX_PIXELS: u64 = 1 << 30;
+
+/// The errors that may occur while creating a canvas.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RasterError {
+    /// The image, of the given width and height in pixels, has more than `MAX_PIXELS` pixels.
+    TooLarge(u64, u64),
+}
+
+impl Display for RasterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RasterError::TooLarge(width, height) => {
+                write!(f, "a {width}x{height} image is too large to be drawn")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RasterError {}
+
+/// An RGB image the mazes are drawn on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Creates a new canvas filled with the given color.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the canvas, in pixels.
+    /// * `height` - The height of the canvas, in pixels.
+    /// * `background` - The color the canvas is filled with.
+    ///
+    /// # Returns
+    ///
+    /// A new `Canvas` instance, or an error if its pixels cannot be held in memory.
+    pub fn new(width: u32, height: u32, background: Rgb) -> Result<Self, RasterError> {
+        let count = usize::try_from(u64::from(width) * u64::from(height))
+            .ok()
+            .filter(|count| {
+                count
+                    .checked_mul(3)
+                    .is_some_and(|len| len <= isize::MAX as usize)
+            })
+            .ok_or(RasterError::TooLarge(width.into(), height.into()))?;
+
+        Ok(Self {
+            width,
+            height,
+            pixels: background.repeat(count),
+        })
+    }
+
+    /// Returns the width of the canvas, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the canvas, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the RGB components of every pixel, row by row.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns the color of the pixel at the given position, if it is inside the canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal position of the pixel.
+    /// * `y` - The vertical position of the pixel.
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = self.index(x, y);
+
+        Some([
+            self.pixels[index],
+            self.pixels[index + 1],
+            self.pixels[index + 2],
+        ])
+    }
+
+    /// Fills a rectangle with the given color; the parts outside the canvas are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The horizontal position of the top left corner.
+    /// * `y` - The vertical position of the top left corner.
+    /// * `width` - The width of the rectangle.
+    /// * `height` - The height of the rectangle.
+    /// * `color` - The color of the rectangle.
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, color: Rgb) {
+        let right = x.saturating_add(width).min(self.width);
+        let bottom = y.saturating_add(height).min(self.height);
+
+        for row in y..bottom {
+            for column in x..right {
+                let index = self.index(column, row);
+                self.pixels[index..index + 3].copy_from_slice(&color);
+            }
+        }
+    }
+
+    /// Returns the index of the first component of a pixel inside the canvas.
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y as usize * self.width as usize + x as usize) * 3
+    }
+
+    /// Encodes the canvas as a PNG image.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The writer the PNG image is written to.
+    ///
+    /// # Returns
+    ///
+    /// An error if the image cannot be encoded or written.
+    pub fn write_png(&self, writer: impl Write) -> Result<(), ::png::EncodingError> {
+        let mut encoder = ::png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(::png::ColorType::Rgb);
+        encoder.set_depth(::png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()
+    }
+
+    /// Saves the canvas as a PNG image at the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the PNG file.
+    ///
+    /// # Returns
+    ///
+    /// An error if the file cannot be created or the image cannot be encoded.
+    pub fn save_png(&self, path: impl AsRef<Path>) -> Result<(), ::png::EncodingError> {
+        let file = File::create(path)?;
+
+        self.write_png(BufWriter::new(file))
+    }
+}
+
+/// Returns the size of a canvas if it has at most `MAX_PIXELS` pixels.
+fn check_size(width: u64, height: u64) -> Result<(u32, u32), RasterError> {
+    match (u32::try_from(width), u32::try_from(height)) {
+        (Ok(narrow), Ok(short)) if width.saturating_mul(height) <= MAX_PIXELS => {
+            Ok((narrow, short))
+        }
+        _ => Err(RasterError::TooLarge(width, height)),
+    }
+}
+
+/// Draws the walls of a grid, and optionally the distances of its cells, on a `Canvas`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RasterRenderer {
+    cell_size: u32,
+    wall_thickness: u32,
+    margin: u32,
+    background: Rgb,
+    wall_color: Rgb,
+}
+
+impl Default for RasterRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RasterRenderer {
+    /// Creates a new renderer drawing black walls on a white background.
+    ///
+    /// # Returns
+    ///
+    /// A new `RasterRenderer` instance.
+    pub fn new() -> Self {
+        Self {
+            cell_size: 20,
+            wall_thickness: 2,
+            margin: 10,
+            background: [255, 255, 255],
+            wall_color: [0, 0, 0],
+        }
+    }
+
+    /// Sets the size, in pixels, of the side of a cell.
+    pub fn with_cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the thickness, in pixels, of the walls.
+    pub fn with_wall_thickness(mut self, wall_thickness: u32) -> Self {
+        self.wall_thickness = wall_thickness;
+        self
+    }
+
+    /// Sets the empty space, in pixels, around the maze.
+    pub fn with_margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the color of the background and of the cells.
+    pub fn with_background(mut self, background: Rgb) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Sets the color of the walls.
+    pub fn with_wall_color(mut self, wall_color: Rgb) -> Self {
+        self.wall_color = wall_color;
+        self
+    }
+
+    /// Returns the size, in pixels, of the side of a cell.
+    pub fn cell_size(&self) -> u32 {
+        self.cell_size
+    }
+
+    /// Returns the position, in pixels, of the top left corner of a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell.
+    /// * `column` - The column of the cell.
+    pub fn cell_origin(&self, row: i32, column: i32) -> (u32, u32) {
+        (
+            self.margin + column as u32 * self.cell_size,
+            self.margin + row as u32 * self.cell_size,
+        )
+    }
+
+    /// Creates an empty canvas large enough to draw the given grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to draw.
+    ///
+    /// # Returns
+    ///
+    /// A `Canvas` filled with the background color, or an error if the image is too large.
+    pub fn canvas(&self, grid: &dyn WithRowsAndColumns) -> Result<Canvas, RasterError> {
+        let (width, height) = self.canvas_size(grid);
+        let (width, height) = check_size(width, height)?;
+
+        Canvas::new(width, height, self.background)
+    }
+
+    /// Returns the width and the height, in pixels, of the canvas of the given grid, which may be
+    /// too large to be drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to draw.
+    pub fn canvas_size(&self, grid: &dyn WithRowsAndColumns) -> (u64, u64) {
+        let side = |cells: i32| {
+            2 * u64::from(self.margin)
+                + cells.max(0) as u64 * u64::from(self.cell_size)
+                + u64::from(self.wall_thickness)
+        };
+
+        (side(grid.columns()), side(grid.rows()))
+    }
+
+    /// Fills a cell, inside its walls, with the given color.
+    ///
+    /// # Arguments
+    ///
+    /// * `canvas` - The canvas to draw on.
+    /// * `cell` - The row and column of the cell.
+    /// * `color` - The color of the cell.
+    pub fn fill_cell(&self, canvas: &mut Canvas, cell: (i32, i32), color: Rgb) {
+        let (x, y) = self.cell_origin(cell.0, cell.1);
+
+        canvas.fill_rect(x, y, self.cell_size, self.cell_size, color);
+    }
+
+    /// Draws the walls of the grid: the boundary of the grid and every side of a cell
+    /// which is not linked to the neighbor on that side.
+    ///
+    /// # Arguments
+    ///
+    /// * `canvas` - The canvas to draw on.
+    /// * `grid` - The grid to draw.
+    pub fn draw_walls(&self, canvas: &mut Canvas, grid: &dyn WithRowsAndColumns) {
+        let size = self.cell_size;
+        let thickness = self.wall_thickness;
+
+        for cell in grid.iter() {
+            let cell = cell.borrow();
+            let (x, y) = self.cell_origin(cell.row(), cell.column());
+            let linked = |neighbor: Option<(i32, i32)>| {
+                neighbor.is_some_and(|neighbor| cell.links().contains_key(&neighbor))
+            };
+
+            if cell.north().is_none() {
+                canvas.fill_rect(x, y, size + thickness, thickness, self.wall_color);
+            }
+
+            if cell.west().is_none() {
+                canvas.fill_rect(x, y, thickness, size + thickness, self.wall_color);
+            }
+
+            if !linked(cell.east()) {
+                canvas.fill_rect(x + size, y, thickness, size + thickness, self.wall_color);
+            }
+
+            if !linked(cell.south()) {
+                canvas.fill_rect(x, y + size, size + thickness, thickness, self.wall_color);
+            }
+        }
+    }
+
+    /// Renders the grid on a new canvas.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to render.
+    /// * `distances` - When present, every cell is filled with the same distance gradient
+    ///   used by `DistanceGrid::display_with_color`.
+    ///
+    /// # Returns
+    ///
+    /// The `Canvas` with the rendered maze, or an error if the image is too large.
+    pub fn render(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        distances: Option<&Distances>,
+    ) -> Result<Canvas, RasterError> {
+        let mut canvas = self.canvas(grid)?;
+
+        if let Some(distances) = distances {
+            let max_distance = distances.max_distance().value();
+
+            for cell in grid.iter() {
+                let cell = cell.borrow().to_row_and_column();
+
+                if let Some(distance) = distances.get(cell) {
+                    self.fill_cell(&mut canvas, cell, distance_color(*distance, max_distance));
+                }
+            }
+        }
+
+        self.draw_walls(&mut canvas, grid);
+
+        Ok(canvas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::grid::Grid;
+
+    const WHITE: Rgb = [255, 255, 255];
+    const BLACK: Rgb = [0, 0, 0];
+
+    fn renderer() -> RasterRenderer {
+        RasterRenderer::new()
+            .with_cell_size(10)
+            .with_wall_thickness(1)
+            .with_margin(2)
+    }
+
+    #[test]
+    fn test_canvas_size() {
+        let grid = Grid::new(3, 4);
+        let canvas = renderer().render(&grid, None).unwrap();
+
+        assert_eq!(canvas.width(), 2 * 2 + 4 * 10 + 1);
+        assert_eq!(canvas.height(), 2 * 2 + 3 * 10 + 1);
+        assert_eq!(canvas.pixels().len(), (45 * 35 * 3) as usize);
+    }
+
+    #[test]
+    fn test_too_large() {
+        let grid = Grid::new(500, 500);
+        let renderer = RasterRenderer::new().with_cell_size(100_000);
+
+        assert_eq!(renderer.canvas_size(&grid), (50_000_022, 50_000_022));
+        assert_eq!(
+            renderer.render(&grid, None),
+            Err(RasterError::TooLarge(50_000_022, 50_000_022))
+        );
+        assert!(Canvas::new(u32::MAX, u32::MAX, WHITE).is_err());
+    }
+
+    #[test]
+    fn test_render_walls() {
+        let grid = Grid::new(1, 2);
+        let canvas = renderer().render(&grid, None).unwrap();
+
+        // The margin, the inside of a cell and the wall between the two cells.
+        assert_eq!(canvas.pixel(0, 0), Some(WHITE));
+        assert_eq!(canvas.pixel(7, 7), Some(WHITE));
+        assert_eq!(canvas.pixel(12, 7), Some(BLACK));
+
+        let first = grid.cell(0, 0).unwrap();
+        first.borrow_mut().link(grid.cell(0, 1).unwrap().clone());
+
+        let canvas = renderer().render(&grid, None).unwrap();
+
+        assert_eq!(canvas.pixel(12, 7), Some(WHITE));
+        assert_eq!(canvas.pixel(2, 7), Some(BLACK));
+        assert_eq!(canvas.pixel(12, 2), Some(BLACK));
+    }
+
+    #[test]
+    fn test_render_distances() {
+        let grid = Grid::new(1, 2);
+        let first = grid.cell(0, 0).unwrap().clone();
+        first.borrow_mut().link(grid.cell(0, 1).unwrap().clone());
+
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(first, &grid);
+
+        let canvas = renderer().render(&grid, Some(&distances)).unwrap();
+
+        assert_eq!(canvas.pixel(7, 7), Some(distance_color(0, 1)));
+        assert_eq!(canvas.pixel(17, 7), Some(distance_color(1, 1)));
+    }
+
+    #[test]
+    fn test_write_png() {
+        let grid = Grid::new(2, 2);
+        let canvas = renderer().render(&grid, None).unwrap();
+        let mut bytes = vec![];
+
+        canvas.write_png(&mut bytes).unwrap();
+
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
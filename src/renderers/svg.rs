@@ -0,0 +1,291 @@
+use crate::{
+    grids::{base_grid::WithRowsAndColumns, distances::Distances, path::Path},
+    utils::color::distance_color,
+};
+use std::fmt::Write;
+
+/// The default style of the layers, which can be overridden by any stylesheet embedding the image.
+const STYLE: &str = ".walls { stroke: black; stroke-linecap: square; }
+.solution { fill: none; stroke: crimson; stroke-linecap: round; stroke-linejoin: round; }";
+
+/// Renders a grid as an SVG image.
+///
+/// The image is made of separate `<g>` layers, each with its own `id` and `class`, so that they
+/// can be restyled or hidden with CSS:
+///
+/// * `heatmap` - A rectangle for every cell, filled with the distance gradient.
+/// * `walls` - A line for every wall.
+/// * `solution` - A polyline through the centers of the cells of the solution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgRenderer {
+    cell_size: u32,
+    wall_thickness: u32,
+    margin: u32,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgRenderer {
+    /// Creates a new SVG renderer.
+    ///
+    /// # Returns
+    ///
+    /// A new `SvgRenderer` instance.
+    pub fn new() -> Self {
+        Self {
+            cell_size: 20,
+            wall_thickness: 2,
+            margin: 10,
+        }
+    }
+
+    /// Sets the size, in user units, of the side of a cell.
+    pub fn with_cell_size(mut self, cell_size: u32) -> Self {
+        self.cell_size = cell_size;
+        self
+    }
+
+    /// Sets the thickness, in user units, of the walls.
+    pub fn with_wall_thickness(mut self, wall_thickness: u32) -> Self {
+        self.wall_thickness = wall_thickness;
+        self
+    }
+
+    /// Sets the empty space, in user units, around the maze.
+    pub fn with_margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Renders the grid as an SVG document.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid to render.
+    /// * `heatmap` - When present, the distances rendered in the `heatmap` layer.
+    /// * `solution` - When present, the path returned by `Distances::path_to`, rendered in the
+    ///   `solution` layer.
+    ///
+    /// # Returns
+    ///
+    /// The SVG document.
+    pub fn render(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        heatmap: Option<&Distances>,
+        solution: Option<&Path>,
+    ) -> String {
+        // The walls are centered on the sides of the cells, so the boundary needs half of its
+        // thickness on each side of the grid to be drawn whole, even without a margin.
+        let width = 2 * self.margin + grid.columns() as u32 * self.cell_size + self.wall_thickness;
+        let height = 2 * self.margin + grid.rows() as u32 * self.cell_size + self.wall_thickness;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        let _ = writeln!(svg, "<style>\n{STYLE}\n</style>");
+
+        if let Some(distances) = heatmap {
+            self.heatmap(&mut svg, grid, distances);
+        }
+
+        self.walls(&mut svg, grid);
+
+        if let Some(path) = solution {
+            self.solution(&mut svg, path);
+        }
+
+        svg.push_str("</svg>\n");
+
+        svg
+    }
+
+    /// Returns the position of the top left corner of a cell, past the margin and half of the
+    /// thickness of the boundary.
+    fn cell_origin(&self, row: i32, column: i32) -> (f64, f64) {
+        let offset = f64::from(self.margin) + f64::from(self.wall_thickness) / 2.0;
+        let size = f64::from(self.cell_size);
+
+        (
+            offset + f64::from(column) * size,
+            offset + f64::from(row) * size,
+        )
+    }
+
+    /// Writes the `heatmap` layer.
+    fn heatmap(&self, svg: &mut String, grid: &dyn WithRowsAndColumns, distances: &Distances) {
+        let max_distance = distances.max_distance().value();
+        let size = self.cell_size;
+
+        svg.push_str(r#"<g id="heatmap" class="heatmap">"#);
+        svg.push('\n');
+
+        for cell in grid.iter() {
+            let (row, column) = cell.borrow().to_row_and_column();
+
+            if let Some(distance) = distances.get((row, column)) {
+                let (x, y) = self.cell_origin(row, column);
+                let [red, green, blue] = distance_color(*distance, max_distance);
+
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{size}" height="{size}" fill="rgb({red},{green},{blue})" data-distance="{distance}"/>"#
+                );
+            }
+        }
+
+        svg.push_str("</g>\n");
+    }
+
+    /// Writes the `walls` layer: the boundary of the grid and every side of a cell which is not
+    /// linked to the neighbor on that side.
+    fn walls(&self, svg: &mut String, grid: &dyn WithRowsAndColumns) {
+        let size = f64::from(self.cell_size);
+
+        let _ = writeln!(
+            svg,
+            r#"<g id="walls" class="walls" stroke-width="{}">"#,
+            self.wall_thickness
+        );
+
+        for cell in grid.iter() {
+            let cell = cell.borrow();
+            let (x1, y1) = self.cell_origin(cell.row(), cell.column());
+            let (x2, y2) = (x1 + size, y1 + size);
+            let linked = |neighbor: Option<(i32, i32)>| {
+                neighbor.is_some_and(|neighbor| cell.links().contains_key(&neighbor))
+            };
+
+            let mut line = |x1: f64, y1: f64, x2: f64, y2: f64| {
+                let _ = writeln!(svg, r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}"/>"#);
+            };
+
+            if cell.north().is_none() {
+                line(x1, y1, x2, y1);
+            }
+
+            if cell.west().is_none() {
+                line(x1, y1, x1, y2);
+            }
+
+            if !linked(cell.east()) {
+                line(x2, y1, x2, y2);
+            }
+
+            if !linked(cell.south()) {
+                line(x1, y2, x2, y2);
+            }
+        }
+
+        svg.push_str("</g>\n");
+    }
+
+    /// Writes the `solution` layer, a line through the cells of the path in order.
+    fn solution(&self, svg: &mut String, path: &Path) {
+        let half = f64::from(self.cell_size) / 2.0;
+
+        let points = path
+            .cells()
+            .iter()
+            .map(|(row, column)| {
+                let (x, y) = self.cell_origin(*row, *column);
+                format!("{},{}", x + half, y + half)
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        let _ = writeln!(
+            svg,
+            r#"<g id="solution" class="solution" stroke-width="{}">"#,
+            (self.cell_size / 4).max(1)
+        );
+        let _ = writeln!(svg, r#"<polyline points="{points}"/>"#);
+        svg.push_str("</g>\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grids::grid::Grid;
+
+    fn corridor() -> Grid {
+        let grid = Grid::new(1, 3);
+
+        for column in 0..2 {
+            let cell = grid.cell(0, column).unwrap();
+            cell.borrow_mut()
+                .link(grid.cell(0, column + 1).unwrap().clone());
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_render_walls() {
+        let grid = corridor();
+        let svg = SvgRenderer::new()
+            .with_cell_size(10)
+            .with_margin(0)
+            .render(&grid, None, None);
+
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="32" height="12""#)
+        );
+        assert!(svg.contains(r#"<g id="walls" class="walls" stroke-width="2">"#));
+        // Three northern walls, three southern walls, the western and the eastern boundary.
+        assert_eq!(svg.matches("<line ").count(), 8);
+        // The boundary is offset by half of its thickness, so that it is not clipped.
+        assert!(svg.contains(r#"<line x1="1" y1="1" x2="11" y2="1"/>"#));
+        assert!(!svg.contains(r#"id="heatmap""#));
+        assert!(!svg.contains(r#"id="solution""#));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_render_layers() {
+        let grid = corridor();
+        let root = grid.cell(0, 0).unwrap().clone();
+        let goal = grid.cell(0, 2).unwrap().clone();
+
+        let mut heatmap = Distances::new((0, 0));
+        heatmap.calculate(root, &grid);
+
+        let solution = heatmap.path_to(goal, &grid).unwrap();
+
+        let svg = SvgRenderer::new().with_cell_size(10).with_margin(0).render(
+            &grid,
+            Some(&heatmap),
+            Some(&solution),
+        );
+
+        assert!(svg.contains(r#"<g id="heatmap" class="heatmap">"#));
+        assert_eq!(svg.matches("<rect ").count(), 3);
+        assert!(svg.contains(r#"fill="rgb(0,0,128)" data-distance="2""#));
+        assert!(svg.contains(r#"<polyline points="6,6 16,6 26,6"/>"#));
+        assert!(svg.find(r#"id="heatmap""#) < svg.find(r#"id="walls""#));
+        assert!(svg.find(r#"id="walls""#) < svg.find(r#"id="solution""#));
+    }
+
+    #[test]
+    fn test_render_odd_wall_thickness() {
+        let grid = corridor();
+        let svg = SvgRenderer::new()
+            .with_cell_size(10)
+            .with_wall_thickness(3)
+            .with_margin(4)
+            .render(&grid, None, None);
+
+        assert!(
+            svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="41" height="21""#)
+        );
+        assert!(svg.contains(r#"<line x1="5.5" y1="5.5" x2="5.5" y2="15.5"/>"#));
+        assert!(svg.contains(r#"<line x1="35.5" y1="5.5" x2="35.5" y2="15.5"/>"#));
+    }
+}
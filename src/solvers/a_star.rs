@@ -0,0 +1,313 @@
+use crate::grids::base_grid::WithRowsAndColumns;
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+/// The estimates of the distance left from a cell to the goal, which lead the A* search towards
+/// the goal.
+///
+/// The estimates never exceed the length of a path between the cells, so the path found is always
+/// a shortest one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Heuristic {
+    /// The number of rows plus the number of columns between the cells.
+    #[default]
+    Manhattan,
+    /// The straight line distance between the centers of the cells.
+    Euclidean,
+    /// No estimate at all: the search expands the cells in order of distance from the start,
+    /// like a breadth-first search.
+    Zero,
+}
+
+impl Heuristic {
+    /// Estimates the distance left from a cell to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The row and the column of the cell.
+    /// * `to` - The row and the column of the goal.
+    ///
+    /// # Returns
+    ///
+    /// The estimated number of passages between the cells.
+    pub fn estimate(self, from: (i32, i32), to: (i32, i32)) -> f64 {
+        let rows = f64::from((from.0 - to.0).abs());
+        let columns = f64::from((from.1 - to.1).abs());
+
+        match self {
+            Heuristic::Manhattan => rows + columns,
+            Heuristic::Euclidean => rows.hypot(columns),
+            Heuristic::Zero => 0.0,
+        }
+    }
+}
+
+/// The result of a search: the path from the start to the goal and the cells expanded to find
+/// it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Search {
+    path: Vec<(i32, i32)>,
+    expanded: Vec<(i32, i32)>,
+}
+
+impl Search {
+    /// Returns the cells of the path, from the start to the goal; empty if the goal is
+    /// unreachable.
+    pub fn path(&self) -> &[(i32, i32)] {
+        &self.path
+    }
+
+    /// Returns the cells expanded by the search, in the order they were expanded, ending with
+    /// the goal when it is reached.
+    pub fn expanded(&self) -> &[(i32, i32)] {
+        &self.expanded
+    }
+
+    /// Returns whether the search reached the goal.
+    pub fn is_solved(&self) -> bool {
+        !self.path.is_empty()
+    }
+}
+
+/// A cell waiting to be expanded, ordered so that the binary heap hands out the lowest
+/// estimated total first.
+#[derive(Debug, PartialEq)]
+struct Candidate {
+    /// The distance from the start plus the estimated distance left.
+    total: f64,
+    /// The estimated distance left, which breaks the ties in favor of the cells closer to the
+    /// goal.
+    left: f64,
+    cell: (i32, i32),
+}
+
+impl Eq for Candidate {}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .total
+            .total_cmp(&self.total)
+            .then_with(|| other.left.total_cmp(&self.left))
+            .then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The A* search finds a shortest path between two cells, expanding first the cells whose
+/// distance from the start plus the estimated distance left to the goal is the lowest.
+///
+/// The better the estimate, the fewer cells are expanded; without any estimate, the search
+/// expands as many cells as a breadth-first search.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AStar {
+    heuristic: Heuristic,
+}
+
+impl AStar {
+    /// Creates a new A* search, with the Manhattan distance as its heuristic.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the estimate of the distance left to the goal.
+    pub fn with_heuristic(mut self, heuristic: Heuristic) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    /// Searches a shortest path between two cells of the maze.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The maze in which the path is searched.
+    /// * `start` - The row and the column of the cell the path starts from.
+    /// * `goal` - The row and the column of the cell the path ends at.
+    ///
+    /// # Returns
+    ///
+    /// The `Search`, with an empty path if the goal is unreachable or a cell is outside the
+    /// grid.
+    pub fn solve(
+        &self,
+        grid: &(impl WithRowsAndColumns + ?Sized),
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Search {
+        let mut search = Search::default();
+
+        if grid.cell(start.0, start.1).is_none() || grid.cell(goal.0, goal.1).is_none() {
+            return search;
+        }
+
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut previous = HashMap::new();
+        let mut closed = HashSet::new();
+        let mut open = BinaryHeap::from([self.candidate(start, 0, goal)]);
+
+        while let Some(Candidate { cell, .. }) = open.pop() {
+            // The cell was queued again, closer, and has already been expanded.
+            if !closed.insert(cell) {
+                continue;
+            }
+
+            search.expanded.push(cell);
+
+            if cell == goal {
+                search.path = path(&previous, goal);
+                break;
+            }
+
+            let distance = distances[&cell] + 1;
+
+            for linked in grid.cell(cell.0, cell.1).unwrap().borrow().links().keys() {
+                if distances.get(linked).is_none_or(|known| distance < *known) {
+                    distances.insert(*linked, distance);
+                    previous.insert(*linked, cell);
+                    open.push(self.candidate(*linked, distance, goal));
+                }
+            }
+        }
+
+        search
+    }
+
+    /// Creates the candidate of a cell reached at the given distance from the start.
+    fn candidate(&self, cell: (i32, i32), distance: i32, goal: (i32, i32)) -> Candidate {
+        let left = self.heuristic.estimate(cell, goal);
+
+        Candidate {
+            total: f64::from(distance) + left,
+            left,
+            cell,
+        }
+    }
+}
+
+/// Walks back from the goal to the start, through the cell every cell was reached from.
+///
+/// # Arguments
+///
+/// * `previous` - The cell every reached cell, but the start, was reached from.
+/// * `goal` - The row and the column of the goal.
+///
+/// # Returns
+///
+/// The cells of the path, from the start to the goal.
+fn path(previous: &HashMap<(i32, i32), (i32, i32)>, goal: (i32, i32)) -> Vec<(i32, i32)> {
+    let mut path = vec![goal];
+
+    while let Some(cell) = previous.get(path.last().unwrap()) {
+        path.push(*cell);
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, On},
+        grids::{braid::Braid, distances::Distances, grid::Grid, test_support::link},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    /// A grid without any wall inside.
+    fn room(size: i32) -> Grid {
+        let grid = Grid::new(size, size);
+
+        for row in 0..size {
+            for column in 0..size {
+                if column + 1 < size {
+                    link(&grid, (row, column), (row, column + 1));
+                }
+
+                if row + 1 < size {
+                    link(&grid, (row, column), (row + 1, column));
+                }
+            }
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_estimate() {
+        assert_eq!(Heuristic::Manhattan.estimate((0, 0), (3, 4)), 7.0);
+        assert_eq!(Heuristic::Euclidean.estimate((0, 0), (3, 4)), 5.0);
+        assert_eq!(Heuristic::Zero.estimate((0, 0), (3, 4)), 0.0);
+    }
+
+    #[test]
+    fn test_solve_corridor() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+
+        let search = AStar::new().solve(&grid, (0, 0), (1, 0));
+
+        assert!(search.is_solved());
+        assert_eq!(search.path(), [(0, 0), (0, 1), (1, 1), (1, 0)]);
+        assert_eq!(search.expanded(), search.path());
+    }
+
+    #[test]
+    fn test_solve_unreachable() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+
+        let search = AStar::new().solve(&grid, (0, 0), (1, 1));
+
+        assert!(!search.is_solved());
+        assert_eq!(search.expanded(), [(0, 0), (0, 1)]);
+        assert!(!AStar::new().solve(&grid, (0, 0), (2, 0)).is_solved());
+    }
+
+    #[test]
+    fn test_shortest_path_with_every_heuristic() {
+        let mut grid = Grid::new(12, 12);
+        RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(3));
+        grid.braid(0.5, &mut StdRng::seed_from_u64(4));
+
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(grid.cell(0, 0).unwrap().clone(), &grid);
+        let shortest = *distances.get((11, 11)).unwrap() as usize;
+
+        for heuristic in [Heuristic::Manhattan, Heuristic::Euclidean, Heuristic::Zero] {
+            let search = AStar::new()
+                .with_heuristic(heuristic)
+                .solve(&grid, (0, 0), (11, 11));
+
+            assert_eq!(search.path().len(), shortest + 1);
+            assert_eq!(search.path().first(), Some(&(0, 0)));
+            assert_eq!(search.path().last(), Some(&(11, 11)));
+        }
+    }
+
+    #[test]
+    fn test_heuristics_expand_fewer_cells() {
+        let grid = room(8);
+        let expanded = |heuristic| {
+            AStar::new()
+                .with_heuristic(heuristic)
+                .solve(&grid, (0, 0), (7, 7))
+                .expanded()
+                .len()
+        };
+
+        // Without walls, the Manhattan distance is exact and only the path is expanded.
+        assert_eq!(expanded(Heuristic::Manhattan), 15);
+        assert!(expanded(Heuristic::Euclidean) < expanded(Heuristic::Zero));
+        assert_eq!(expanded(Heuristic::Zero), 64);
+    }
+}
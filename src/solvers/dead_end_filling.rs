@@ -0,0 +1,185 @@
+use super::{
+    passages,
+    traversal::{Move, Outcome, Traversal},
+    Solve,
+};
+use crate::grids::base_grid::WithRowsAndColumns;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The dead-end filling fills every dead end of the maze but the start and the goal, then the
+/// cells that became dead ends, until none is left: the cells left open are the solution.
+///
+/// It looks at the whole maze at once, like a person with a map and a pencil, rather than
+/// walking through it. In a maze with loops, the loops stay open, and the path is the shortest
+/// one through the open cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DeadEndFilling;
+
+impl DeadEndFilling {
+    /// Creates a new dead-end filling solver.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Solve for DeadEndFilling {
+    fn solve(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Traversal {
+        if grid.cell(start.0, start.1).is_none() || grid.cell(goal.0, goal.1).is_none() {
+            return Traversal::new(vec![], vec![], Outcome::Unreachable);
+        }
+
+        let mut filled = HashSet::new();
+        let open = |filled: &HashSet<(i32, i32)>, cell: (i32, i32)| {
+            passages(grid, cell)
+                .into_iter()
+                .filter(|neighbor| !filled.contains(neighbor))
+                .collect::<Vec<(i32, i32)>>()
+        };
+
+        let mut dead_ends = grid
+            .iter()
+            .map(|cell| cell.borrow().to_row_and_column())
+            .filter(|cell| *cell != start && *cell != goal)
+            .filter(|cell| open(&filled, *cell).len() == 1)
+            .collect::<VecDeque<(i32, i32)>>();
+
+        let mut moves = vec![];
+
+        while let Some(cell) = dead_ends.pop_front() {
+            let neighbors = open(&filled, cell);
+
+            if neighbors.len() != 1 || !filled.insert(cell) {
+                continue;
+            }
+
+            moves.push(Move::Filled(cell));
+
+            // The only neighbor left open may become a dead end in turn.
+            let neighbor = neighbors[0];
+
+            if neighbor != start && neighbor != goal && open(&filled, neighbor).len() == 1 {
+                dead_ends.push_back(neighbor);
+            }
+        }
+
+        let path = path(grid, &filled, start, goal);
+        let outcome = if path.is_empty() {
+            Outcome::Unreachable
+        } else {
+            Outcome::Solved
+        };
+
+        Traversal::new(moves, path, outcome)
+    }
+}
+
+/// Finds the shortest path between two cells through the cells left open, with a breadth-first
+/// search.
+///
+/// # Arguments
+///
+/// * `grid` - The maze.
+/// * `filled` - The filled cells.
+/// * `start` - The row and the column of the start.
+/// * `goal` - The row and the column of the goal.
+///
+/// # Returns
+///
+/// The cells of the path, from the start to the goal; empty if the goal cannot be reached.
+fn path(
+    grid: &dyn WithRowsAndColumns,
+    filled: &HashSet<(i32, i32)>,
+    start: (i32, i32),
+    goal: (i32, i32),
+) -> Vec<(i32, i32)> {
+    let mut previous = HashMap::from([(start, start)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(cell) = queue.pop_front() {
+        if cell == goal {
+            let mut path = vec![goal];
+
+            while *path.last().unwrap() != start {
+                path.push(previous[path.last().unwrap()]);
+            }
+
+            path.reverse();
+            return path;
+        }
+
+        for neighbor in passages(grid, cell) {
+            if !filled.contains(&neighbor) && !previous.contains_key(&neighbor) {
+                previous.insert(neighbor, cell);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, On},
+        grids::{
+            distances::Distances,
+            grid::Grid,
+            test_support::{fork, link},
+        },
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_fills_dead_ends() {
+        // +---+---+---+
+        // |           |
+        // +---+   +   +
+        // |       |   |
+        // +---+---+---+
+        let grid = fork(Grid::new(2, 3));
+
+        let traversal = DeadEndFilling::new().solve(&grid, (0, 0), (1, 2));
+
+        // The dead end of the south west cell is filled, then the cell it opened on.
+        assert!(traversal.is_solved());
+        assert_eq!(
+            traversal.moves(),
+            [Move::Filled((1, 0)), Move::Filled((1, 1))]
+        );
+        assert_eq!(traversal.path(), [(0, 0), (0, 1), (0, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn test_leaves_only_the_solution() {
+        let mut grid = Grid::new(10, 10);
+        RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(7));
+
+        let traversal = DeadEndFilling::new().solve(&grid, (0, 0), (9, 9));
+
+        let mut distances = Distances::new((0, 0));
+        distances.calculate(grid.cell(0, 0).unwrap().clone(), &grid);
+        let length = *distances.get((9, 9)).unwrap() as usize + 1;
+
+        // In a perfect maze, every cell but the solution is filled.
+        assert_eq!(traversal.path().len(), length);
+        assert_eq!(traversal.moves().len(), 100 - length);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+
+        let traversal = DeadEndFilling::new().solve(&grid, (0, 0), (1, 1));
+
+        assert_eq!(traversal.outcome(), Outcome::Unreachable);
+        assert!(traversal.path().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use self::{
+    dead_end_filling::DeadEndFilling,
+    traversal::Traversal,
+    tremaux::Tremaux,
+    wall_follower::{Hand, WallFollower},
+};
+use crate::grids::base_grid::WithRowsAndColumns;
+use std::fmt::Display;
+
+pub mod a_star;
+pub mod dead_end_filling;
+pub mod traversal;
+pub mod tremaux;
+pub mod wall_follower;
+
+/// The `Solve` trait is implemented by the solvers that find their way through a maze move by
+/// move: some walk it like a person would, knowing only the passages of the cell they are in,
+/// while others, like the dead-end filling, look at the whole maze.
+pub trait Solve {
+    /// Solves the maze from the start to the goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The maze to solve.
+    /// * `start` - The row and the column of the cell the solver starts from.
+    /// * `goal` - The row and the column of the cell the solver looks for.
+    ///
+    /// # Returns
+    ///
+    /// The `Traversal`: every move of the solver and the path it found.
+    fn solve(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Traversal;
+}
+
+/// The solvers that find their way through a maze.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Solvers {
+    WallFollower(WallFollower),
+    Tremaux(Tremaux),
+    DeadEndFilling(DeadEndFilling),
+}
+
+impl Solve for Solvers {
+    fn solve(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Traversal {
+        match self {
+            Solvers::WallFollower(solver) => solver.solve(grid, start, goal),
+            Solvers::Tremaux(solver) => solver.solve(grid, start, goal),
+            Solvers::DeadEndFilling(solver) => solver.solve(grid, start, goal),
+        }
+    }
+}
+
+impl Display for Solvers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solvers::WallFollower(solver) => match solver.hand() {
+                Hand::Left => write!(f, "Wall Follower (left hand)"),
+                Hand::Right => write!(f, "Wall Follower (right hand)"),
+            },
+            Solvers::Tremaux(_) => write!(f, "Tremaux"),
+            Solvers::DeadEndFilling(_) => write!(f, "Dead End Filling"),
+        }
+    }
+}
+
+/// A passage between two linked cells, the smallest cell first.
+pub(crate) type Passage = ((i32, i32), (i32, i32));
+
+/// Returns the passage between two linked cells, the same whichever way it is crossed.
+pub(crate) fn passage(from: (i32, i32), to: (i32, i32)) -> Passage {
+    (from.min(to), from.max(to))
+}
+
+/// Returns the cells linked to a cell, in the order north, east, south and west.
+///
+/// # Arguments
+///
+/// * `grid` - The maze.
+/// * `cell` - The row and the column of the cell.
+///
+/// # Returns
+///
+/// The row and the column of every linked neighbor; none if the cell is outside the grid.
+pub(crate) fn passages(grid: &dyn WithRowsAndColumns, cell: (i32, i32)) -> Vec<(i32, i32)> {
+    let Some(cell) = grid.cell(cell.0, cell.1) else {
+        return vec![];
+    };
+    let cell = cell.borrow();
+
+    [cell.north(), cell.east(), cell.south(), cell.west()]
+        .into_iter()
+        .flatten()
+        .filter(|neighbor| cell.links().contains_key(neighbor))
+        .collect()
+}
@@ -0,0 +1,188 @@
+use super::{passage, Passage};
+use std::collections::{HashMap, HashSet};
+
+/// A move of a solver through a maze, reported in order so that the solving can be shown step by
+/// step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Move {
+    /// The solver entered a cell through a passage it had never crossed.
+    Entered((i32, i32)),
+    /// The solver went back to a cell through a passage it had already crossed.
+    Backtracked((i32, i32)),
+    /// The solver filled a cell, which cannot be on the path.
+    Filled((i32, i32)),
+}
+
+/// How a traversal ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The solver reached the goal.
+    Solved,
+    /// The solver came back to a cell, facing the same way, so it would walk the same loop
+    /// forever without reaching the goal.
+    Lost,
+    /// The solver ran out of passages to explore, or a cell was outside the grid: the goal
+    /// cannot be reached from the start.
+    Unreachable,
+}
+
+/// The moves of a solver from the start to the goal, and the path it found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traversal {
+    moves: Vec<Move>,
+    path: Vec<(i32, i32)>,
+    outcome: Outcome,
+}
+
+impl Traversal {
+    /// Creates a new `Traversal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `moves` - The moves of the solver, in order.
+    /// * `path` - The cells of the path found, from the start to the goal; empty when the goal
+    ///   was not reached.
+    /// * `outcome` - How the traversal ended.
+    ///
+    /// # Returns
+    ///
+    /// A new `Traversal` instance.
+    pub fn new(moves: Vec<Move>, path: Vec<(i32, i32)>, outcome: Outcome) -> Self {
+        Self {
+            moves,
+            path,
+            outcome,
+        }
+    }
+
+    /// Returns the moves of the solver, in order.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Returns the cells of the path found, from the start to the goal; empty when the goal was
+    /// not reached.
+    pub fn path(&self) -> &[(i32, i32)] {
+        &self.path
+    }
+
+    /// Returns how the traversal ended.
+    pub fn outcome(&self) -> Outcome {
+        self.outcome
+    }
+
+    /// Returns whether the solver reached the goal.
+    pub fn is_solved(&self) -> bool {
+        self.outcome == Outcome::Solved
+    }
+}
+
+/// Records the walk of a solver that moves from cell to cell, like a person in the maze.
+///
+/// The path is the walk without its loops: when the solver comes back to a cell of the path,
+/// the cells walked since then are dropped.
+pub(crate) struct Walk {
+    moves: Vec<Move>,
+    crossed: HashSet<Passage>,
+    path: Vec<(i32, i32)>,
+    /// The index of every cell of the path, so that a cell is found without scanning the path.
+    indices: HashMap<(i32, i32), usize>,
+}
+
+impl Walk {
+    /// Starts a walk from the given cell.
+    pub(crate) fn new(start: (i32, i32)) -> Self {
+        Self {
+            moves: vec![Move::Entered(start)],
+            crossed: HashSet::new(),
+            path: vec![start],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Moves the solver through the passage between two linked cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The cell the solver leaves.
+    /// * `to` - The cell the solver enters.
+    pub(crate) fn step(&mut self, from: (i32, i32), to: (i32, i32)) {
+        if self.crossed.insert(passage(from, to)) {
+            self.moves.push(Move::Entered(to));
+        } else {
+            self.moves.push(Move::Backtracked(to));
+        }
+
+        match self.indices.get(&to) {
+            Some(&index) => {
+                for cell in self.path.drain(index + 1..) {
+                    self.indices.remove(&cell);
+                }
+            }
+            None => {
+                self.indices.insert(to, self.path.len());
+                self.path.push(to);
+            }
+        }
+    }
+
+    /// Ends the walk.
+    ///
+    /// # Arguments
+    ///
+    /// * `outcome` - How the walk ended.
+    ///
+    /// # Returns
+    ///
+    /// The `Traversal` of the walk, with a path only if the goal was reached.
+    pub(crate) fn finish(self, outcome: Outcome) -> Traversal {
+        let path = if outcome == Outcome::Solved {
+            self.path
+        } else {
+            vec![]
+        };
+
+        Traversal::new(self.moves, path, outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_walk() {
+        let mut walk = Walk::new((0, 0));
+        walk.step((0, 0), (0, 1));
+        walk.step((0, 1), (1, 1));
+        walk.step((1, 1), (0, 1));
+        walk.step((0, 1), (0, 2));
+
+        let traversal = walk.finish(Outcome::Solved);
+
+        assert!(traversal.is_solved());
+        assert_eq!(
+            traversal.moves(),
+            [
+                Move::Entered((0, 0)),
+                Move::Entered((0, 1)),
+                Move::Entered((1, 1)),
+                Move::Backtracked((0, 1)),
+                Move::Entered((0, 2)),
+            ]
+        );
+        assert_eq!(traversal.path(), [(0, 0), (0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn test_walk_without_goal() {
+        let mut walk = Walk::new((0, 0));
+        walk.step((0, 0), (1, 0));
+
+        let traversal = walk.finish(Outcome::Lost);
+
+        assert!(!traversal.is_solved());
+        assert_eq!(traversal.moves().len(), 2);
+        assert!(traversal.path().is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+use super::{
+    passage, passages,
+    traversal::{Outcome, Traversal, Walk},
+    Passage, Solve,
+};
+use crate::grids::base_grid::WithRowsAndColumns;
+use std::collections::{HashMap, HashSet};
+
+/// The Trémaux's algorithm marks every passage each time it crosses it, and never crosses a
+/// passage marked twice:
+///
+/// * at a cell seen for the first time, it takes an unmarked passage, the first one clockwise
+///   from the north;
+/// * at a cell already seen, it turns back if it came through a new passage, so that it never
+///   walks around a loop twice;
+/// * otherwise it prefers an unmarked passage, then the passage marked once.
+///
+/// It always finds the goal, even in a maze with loops, and it knows that the goal is
+/// unreachable when every passage it can reach is marked twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tremaux;
+
+impl Tremaux {
+    /// Creates a new Trémaux's solver.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Solve for Tremaux {
+    fn solve(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Traversal {
+        let mut walk = Walk::new(start);
+
+        if grid.cell(start.0, start.1).is_none() || grid.cell(goal.0, goal.1).is_none() {
+            return walk.finish(Outcome::Unreachable);
+        }
+
+        let mut marks: HashMap<Passage, u8> = HashMap::new();
+        let mark = |marks: &HashMap<Passage, u8>, from: (i32, i32), to: (i32, i32)| {
+            marks.get(&passage(from, to)).copied().unwrap_or(0)
+        };
+
+        let mut seen = HashSet::from([start]);
+        let mut current = start;
+        let mut previous: Option<(i32, i32)> = None;
+        let mut revisited = false;
+
+        while current != goal {
+            let next = match previous {
+                Some(previous) if revisited && mark(&marks, current, previous) == 1 => {
+                    Some(previous)
+                }
+                _ => passages(grid, current)
+                    .into_iter()
+                    .filter(|neighbor| mark(&marks, current, *neighbor) < 2)
+                    .min_by_key(|neighbor| mark(&marks, current, *neighbor)),
+            };
+
+            let Some(next) = next else {
+                return walk.finish(Outcome::Unreachable);
+            };
+
+            *marks.entry(passage(current, next)).or_default() += 1;
+            walk.step(current, next);
+
+            previous = Some(current);
+            current = next;
+            revisited = !seen.insert(current);
+        }
+
+        walk.finish(Outcome::Solved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, On},
+        grids::{braid::Braid, grid::Grid, test_support::link},
+        solvers::traversal::Move,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_turns_back_at_a_loop() {
+        // A loop around the four north cells of a 3x2 grid, and the goal south of it.
+        let grid = Grid::new(3, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+        link(&grid, (1, 1), (1, 0));
+        link(&grid, (1, 0), (0, 0));
+        link(&grid, (1, 0), (2, 0));
+
+        let traversal = Tremaux::new().solve(&grid, (0, 0), (2, 0));
+
+        // Back at the start through a new passage, the solver turns back instead of walking
+        // around the loop again.
+        assert!(traversal.is_solved());
+        assert_eq!(
+            traversal.moves(),
+            [
+                Move::Entered((0, 0)),
+                Move::Entered((0, 1)),
+                Move::Entered((1, 1)),
+                Move::Entered((1, 0)),
+                Move::Entered((0, 0)),
+                Move::Backtracked((1, 0)),
+                Move::Entered((2, 0)),
+            ]
+        );
+        assert_eq!(traversal.path(), [(0, 0), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn test_solves_braided_mazes() {
+        for seed in 0..5 {
+            let mut grid = Grid::new(10, 10);
+            RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(seed));
+            grid.braid(0.5, &mut StdRng::seed_from_u64(seed));
+
+            let traversal = Tremaux::new().solve(&grid, (0, 0), (9, 9));
+
+            assert!(traversal.is_solved());
+            assert_eq!(traversal.path().first(), Some(&(0, 0)));
+            assert_eq!(traversal.path().last(), Some(&(9, 9)));
+
+            // Every passage is crossed at most twice, once in each direction.
+            let passages = grid
+                .iter()
+                .map(|cell| cell.borrow().links().len())
+                .sum::<usize>();
+
+            assert!(traversal.moves().len() <= passages + 1);
+        }
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid = Grid::new(2, 2);
+        link(&grid, (0, 0), (0, 1));
+        link(&grid, (0, 1), (1, 1));
+
+        let traversal = Tremaux::new().solve(&grid, (0, 0), (1, 0));
+
+        // Every passage is explored, there and back.
+        assert_eq!(traversal.outcome(), Outcome::Unreachable);
+        assert_eq!(traversal.moves().len(), 5);
+        assert!(traversal.path().is_empty());
+    }
+}
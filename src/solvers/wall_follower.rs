@@ -0,0 +1,250 @@
+use super::{
+    traversal::{Outcome, Traversal, Walk},
+    Solve,
+};
+use crate::grids::{base_grid::WithRowsAndColumns, cell::Cell};
+use std::collections::HashSet;
+
+/// The hand kept on the wall.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Hand {
+    #[default]
+    Left,
+    Right,
+}
+
+/// The direction the solver faces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Returns the direction on the left.
+    fn left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Returns the direction on the right.
+    fn right(self) -> Self {
+        self.left().left().left()
+    }
+
+    /// Returns the direction behind.
+    fn back(self) -> Self {
+        self.left().left()
+    }
+
+    /// Returns the neighbor of a cell in this direction, if it exists.
+    fn neighbor(self, cell: &Cell) -> Option<(i32, i32)> {
+        match self {
+            Direction::North => cell.north(),
+            Direction::East => cell.east(),
+            Direction::South => cell.south(),
+            Direction::West => cell.west(),
+        }
+    }
+}
+
+/// The wall follower keeps a hand on the wall and walks until it reaches the goal: at every
+/// cell, it turns towards its hand if it can, goes straight otherwise, then turns away from its
+/// hand, and turns back only at a dead end. It starts facing north.
+///
+/// It always solves a perfect maze, but a maze with loops can trap it around a loop that does
+/// not touch the goal: it is then lost, as soon as it enters a cell facing the same way twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WallFollower {
+    hand: Hand,
+}
+
+impl WallFollower {
+    /// Creates a new wall follower, with its left hand on the wall.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the hand kept on the wall.
+    pub fn with_hand(mut self, hand: Hand) -> Self {
+        self.hand = hand;
+        self
+    }
+
+    /// Returns the hand kept on the wall.
+    pub fn hand(&self) -> Hand {
+        self.hand
+    }
+}
+
+impl Solve for WallFollower {
+    fn solve(
+        &self,
+        grid: &dyn WithRowsAndColumns,
+        start: (i32, i32),
+        goal: (i32, i32),
+    ) -> Traversal {
+        let mut walk = Walk::new(start);
+
+        if grid.cell(start.0, start.1).is_none() || grid.cell(goal.0, goal.1).is_none() {
+            return walk.finish(Outcome::Unreachable);
+        }
+
+        let mut current = start;
+        let mut facing = Direction::North;
+        let mut seen = HashSet::from([(current, facing)]);
+
+        while current != goal {
+            let cell = grid.cell(current.0, current.1).unwrap().borrow();
+            let turns = match self.hand {
+                Hand::Left => [facing.left(), facing, facing.right(), facing.back()],
+                Hand::Right => [facing.right(), facing, facing.left(), facing.back()],
+            };
+
+            let Some((direction, next)) = turns.into_iter().find_map(|direction| {
+                direction
+                    .neighbor(&cell)
+                    .filter(|neighbor| cell.links().contains_key(neighbor))
+                    .map(|neighbor| (direction, neighbor))
+            }) else {
+                // A cell without any passage.
+                return walk.finish(Outcome::Unreachable);
+            };
+
+            walk.step(current, next);
+            current = next;
+            facing = direction;
+
+            if !seen.insert((current, facing)) {
+                return walk.finish(Outcome::Lost);
+            }
+        }
+
+        walk.finish(Outcome::Solved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::{recursive_backtracker::RecursiveBacktracker, On},
+        grids::{
+            distances::Distances,
+            grid::Grid,
+            test_support::{fork, link},
+        },
+        solvers::traversal::Move,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_hands() {
+        let grid = fork(Grid::new(2, 3));
+
+        // The right hand turns south, into the dead end, then comes back.
+        let right = WallFollower::new()
+            .with_hand(Hand::Right)
+            .solve(&grid, (0, 0), (1, 2));
+
+        assert!(right.is_solved());
+        assert_eq!(
+            right.moves(),
+            [
+                Move::Entered((0, 0)),
+                Move::Entered((0, 1)),
+                Move::Entered((1, 1)),
+                Move::Entered((1, 0)),
+                Move::Backtracked((1, 1)),
+                Move::Backtracked((0, 1)),
+                Move::Entered((0, 2)),
+                Move::Entered((1, 2)),
+            ]
+        );
+        assert_eq!(right.path(), [(0, 0), (0, 1), (0, 2), (1, 2)]);
+
+        // The left hand goes straight to the goal.
+        let left = WallFollower::new().solve(&grid, (0, 0), (1, 2));
+
+        assert_eq!(left.moves().len(), 4);
+        assert_eq!(left.path(), right.path());
+    }
+
+    #[test]
+    fn test_solves_perfect_mazes() {
+        for seed in 0..5 {
+            let mut grid = Grid::new(8, 8);
+            RecursiveBacktracker::new().on(&mut grid, &mut StdRng::seed_from_u64(seed));
+
+            let mut distances = Distances::new((0, 0));
+            distances.calculate(grid.cell(0, 0).unwrap().clone(), &grid);
+
+            for hand in [Hand::Left, Hand::Right] {
+                let traversal = WallFollower::new()
+                    .with_hand(hand)
+                    .solve(&grid, (0, 0), (7, 7));
+
+                // A perfect maze has a single path, whatever the walk.
+                assert!(traversal.is_solved());
+                assert_eq!(
+                    traversal.path().len() as i32,
+                    distances.get((7, 7)).unwrap() + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lost_around_a_loop() {
+        // A loop around the walled center of the three west columns, and a goal east of the
+        // loop.
+        let grid = Grid::new(3, 4);
+
+        for (from, to) in [
+            ((0, 0), (0, 1)),
+            ((0, 1), (0, 2)),
+            ((0, 2), (1, 2)),
+            ((1, 2), (2, 2)),
+            ((2, 2), (2, 1)),
+            ((2, 1), (2, 0)),
+            ((2, 0), (1, 0)),
+            ((1, 0), (0, 0)),
+        ] {
+            link(&grid, from, to);
+        }
+
+        link(&grid, (1, 2), (1, 3));
+
+        // Both hands follow the walls of the center, and never leave the loop.
+        for hand in [Hand::Left, Hand::Right] {
+            let traversal = WallFollower::new()
+                .with_hand(hand)
+                .solve(&grid, (0, 1), (1, 3));
+
+            assert_eq!(traversal.outcome(), Outcome::Lost);
+            assert!(traversal.path().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid = Grid::new(2, 2);
+
+        assert_eq!(
+            WallFollower::new().solve(&grid, (0, 0), (1, 1)).outcome(),
+            Outcome::Unreachable
+        );
+        assert_eq!(
+            WallFollower::new()
+                .solve(&fork(Grid::new(2, 3)), (0, 0), (2, 2))
+                .outcome(),
+            Outcome::Unreachable
+        );
+    }
+}
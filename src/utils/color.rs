@@ -0,0 +1,44 @@
+/// A color expressed as its red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// Returns the color of a cell in the distance gradient.
+///
+/// The root is rendered in white, which darkens to a navy blue as the distance approaches the
+/// maximum distance.
+///
+/// # Arguments
+///
+/// * `distance` - The distance of the cell from the root cell.
+/// * `max_distance` - The maximum distance of any cell from the root cell.
+///
+/// # Returns
+///
+/// The color of the cell.
+pub fn distance_color(distance: i32, max_distance: i32) -> Rgb {
+    if max_distance == 0 {
+        return [255, 255, 255];
+    }
+
+    let intensity = f64::from(max_distance - distance) / f64::from(max_distance);
+    let dark = (255.0 * intensity).floor();
+    let bright = 128.0 + (127.0 * intensity).floor();
+
+    [dark as u8, dark as u8, bright as u8]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance_color() {
+        assert_eq!(distance_color(0, 10), [255, 255, 255]);
+        assert_eq!(distance_color(10, 10), [0, 0, 128]);
+        assert_eq!(distance_color(5, 10), [127, 127, 191]);
+    }
+
+    #[test]
+    fn test_distance_color_without_distances() {
+        assert_eq!(distance_color(0, 0), [255, 255, 255]);
+    }
+}
@@ -0,0 +1,228 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A disjoint-set (union-find) structure, which partitions its items into sets that can be
+/// merged together.
+///
+/// Merging two sets compresses the paths of their items to their representatives, and sets are
+/// merged by rank, so that both operations run in almost constant time. Queries only read the
+/// structure, so they can be answered through a shared reference.
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSet<T> {
+    parents: HashMap<T, T>,
+    ranks: HashMap<T, usize>,
+    sizes: HashMap<T, usize>,
+}
+
+impl<T> DisjointSet<T>
+where
+    T: Copy + Eq + Hash,
+{
+    /// Creates a new, empty, disjoint set.
+    ///
+    /// # Returns
+    ///
+    /// A new `DisjointSet` instance.
+    pub fn new() -> Self {
+        Self {
+            parents: HashMap::new(),
+            ranks: HashMap::new(),
+            sizes: HashMap::new(),
+        }
+    }
+
+    /// Adds an item in a set of its own; does nothing if the item is already present.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to add.
+    pub fn insert(&mut self, item: T) {
+        if self.parents.contains_key(&item) {
+            return;
+        }
+
+        self.parents.insert(item, item);
+        self.ranks.insert(item, 0);
+        self.sizes.insert(item, 1);
+    }
+
+    /// Returns whether the item is present.
+    pub fn contains(&self, item: T) -> bool {
+        self.parents.contains_key(&item)
+    }
+
+    /// Returns the representative of the set the item belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to look for.
+    ///
+    /// # Returns
+    ///
+    /// The representative of the set, or `None` if the item is not present.
+    pub fn find(&self, item: T) -> Option<T> {
+        let mut root = *self.parents.get(&item)?;
+
+        while self.parents[&root] != root {
+            root = self.parents[&root];
+        }
+
+        Some(root)
+    }
+
+    /// Returns the representative of the set the item belongs to, and points every item on the
+    /// way straight at it.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to look for.
+    ///
+    /// # Returns
+    ///
+    /// The representative of the set, or `None` if the item is not present.
+    fn compress(&mut self, item: T) -> Option<T> {
+        let root = self.find(item)?;
+        let mut current = item;
+
+        while current != root {
+            let parent = self.parents[&current];
+            self.parents.insert(current, root);
+            current = parent;
+        }
+
+        Some(root)
+    }
+
+    /// Returns whether two items belong to the same set.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The first item.
+    /// * `right` - The second item.
+    ///
+    /// # Returns
+    ///
+    /// `true` if both items are present and belong to the same set.
+    pub fn connected(&self, left: T, right: T) -> bool {
+        match (self.find(left), self.find(right)) {
+            (Some(left), Some(right)) => left == right,
+            _ => false,
+        }
+    }
+
+    /// Merges the sets of two items.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The first item.
+    /// * `right` - The second item.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the sets were merged, `false` if the items already belong to the same set or
+    /// one of them is not present.
+    pub fn union(&mut self, left: T, right: T) -> bool {
+        let (Some(left), Some(right)) = (self.compress(left), self.compress(right)) else {
+            return false;
+        };
+
+        if left == right {
+            return false;
+        }
+
+        let (parent, child) = if self.ranks[&left] < self.ranks[&right] {
+            (right, left)
+        } else {
+            (left, right)
+        };
+
+        if self.ranks[&parent] == self.ranks[&child] {
+            *self.ranks.get_mut(&parent).unwrap() += 1;
+        }
+
+        let size = self.sizes.remove(&child).unwrap_or(0);
+        *self.sizes.get_mut(&parent).unwrap() += size;
+        self.parents.insert(child, parent);
+
+        true
+    }
+
+    /// Returns the number of items in the set the item belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to look for.
+    ///
+    /// # Returns
+    ///
+    /// The size of the set, or `0` if the item is not present.
+    pub fn size_of(&self, item: T) -> usize {
+        self.find(item)
+            .and_then(|root| self.sizes.get(&root).copied())
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut set = DisjointSet::new();
+        set.insert(1);
+        set.insert(2);
+        set.insert(1);
+
+        assert!(set.contains(1));
+        assert!(!set.contains(3));
+        assert_eq!(set.count(), 2);
+        assert_eq!(set.find(1), Some(1));
+        assert_eq!(set.find(3), None);
+    }
+
+    #[test]
+    fn test_union() {
+        let mut set = DisjointSet::new();
+
+        for item in 0..5 {
+            set.insert(item);
+        }
+
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert!(!set.union(0, 7));
+
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.size_of(2), 4);
+        assert_eq!(set.size_of(4), 1);
+        assert_eq!(set.count(), 2);
+    }
+
+    #[test]
+    fn test_find_after_union() {
+        let mut set = DisjointSet::new();
+
+        for item in 0..8 {
+            set.insert(item);
+        }
+
+        for item in 0..7 {
+            set.union(item, item + 1);
+        }
+
+        let set = &set;
+        let root = set.find(0);
+
+        assert!(root.is_some());
+        assert!((0..8).all(|item| set.find(item) == root));
+        assert_eq!(set.size_of(7), 8);
+    }
+}
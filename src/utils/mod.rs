@@ -1 +1,3 @@
+pub mod color;
+pub mod disjoint_set;
 pub mod random;
@@ -1,17 +1,17 @@
 use crate::grids::base_grid::{BaseGrid, GridCell};
-use rand::Rng;
+use rand::{Rng, RngCore};
 
 /// Returns a random cell from the given grid.
 ///
 /// # Arguments
 ///
 /// * `grid` - The grid from which to select a random cell.
+/// * `rng` - The random number generator used to pick the cell.
 ///
 /// # Returns
 ///
 /// The randomly selected `GridCell`.
-pub fn random_cell(grid: &dyn BaseGrid) -> GridCell {
-    let mut rng = rand::thread_rng();
+pub fn random_cell(grid: &dyn BaseGrid, rng: &mut dyn RngCore) -> GridCell {
     let row = rng.gen_range(0..grid.rows());
     let column = rng.gen_range(0..grid.columns());
 
@@ -24,13 +24,16 @@ pub fn random_cell(grid: &dyn BaseGrid) -> GridCell {
 ///
 /// * `grid` - The grid from which to select a random neighbor cell.
 /// * `neighbors` - A list of coordinates representing the neighbors of a cell.
+/// * `rng` - The random number generator used to pick the neighbor.
 ///
 /// # Returns
 ///
 /// The randomly selected neighbor `GridCell`.
-pub fn random_neighbor(grid: &dyn BaseGrid, neighbors: &Vec<(i32, i32)>) -> GridCell {
-    let mut rng = rand::thread_rng();
-
+pub fn random_neighbor(
+    grid: &dyn BaseGrid,
+    neighbors: &[(i32, i32)],
+    rng: &mut dyn RngCore,
+) -> GridCell {
     let index = rng.gen_range(0..neighbors.len());
     let (row, column) = neighbors[index];
 